@@ -0,0 +1,11 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use pinocchio_mpl_utils::token::assert_holder;
+
+/// Asserts that `owner` holds at least one token of `mint` in `token_account`.
+pub fn process_assert_holder(accounts: &[AccountInfo]) -> ProgramResult {
+    let [token_account, owner, mint] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    assert_holder(token_account, owner, mint, ProgramError::InvalidAccountData)
+}
@@ -0,0 +1,17 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use pinocchio_mpl_utils::token::assert_is_ata;
+
+/// Asserts that `ata` is the associated token account of `wallet` for `mint`.
+pub fn process_assert_is_ata(accounts: &[AccountInfo]) -> ProgramResult {
+    let [wallet, mint, token_program, ata] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    assert_is_ata(
+        wallet,
+        mint,
+        token_program,
+        ata,
+        ProgramError::InvalidAccountData,
+    )
+}
@@ -0,0 +1,47 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use pinocchio_mpl_utils::{
+    assert_signer, create_or_allocate_account_keypair, create_or_allocate_account_signed,
+    SignerSeeds,
+};
+
+/// Creates the `["account", payer]` PDA with `size` bytes of data, owned by
+/// `owner_program`. The bump comes with the instruction data.
+pub fn process_create_signed_account(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [payer, new_account, owner_program, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if data.len() < 9 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    let bump = data[0];
+    let size = u64::from_le_bytes(data[1..9].try_into().unwrap()) as usize;
+
+    assert_signer(payer)?;
+
+    create_or_allocate_account_signed(
+        owner_program.key(),
+        new_account,
+        system_program,
+        payer,
+        size,
+        &SignerSeeds::new(&[b"account", payer.key().as_ref()], Some(bump))?,
+    )
+}
+
+/// Creates `new_account` with `size` bytes of data, owned by this program.
+/// `new_account` is a keypair that has to sign the transaction.
+pub fn process_create_keypair_account(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [payer, new_account, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if data.len() < 8 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    let size = u64::from_le_bytes(data[0..8].try_into().unwrap()) as usize;
+
+    assert_signer(payer)?;
+
+    create_or_allocate_account_keypair(&crate::ID, new_account, system_program, payer, size)
+}
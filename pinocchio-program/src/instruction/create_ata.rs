@@ -0,0 +1,36 @@
+use pinocchio::{
+    account_info::AccountInfo, cpi::set_return_data, program_error::ProgramError, ProgramResult,
+};
+use pinocchio_mpl_utils::{
+    assert_signer,
+    token::{create_ata_if_needed, CreateAtaParams},
+};
+
+/// Creates the associated token account of `wallet` for `mint` if it doesn't
+/// exist yet and returns its amount.
+pub fn process_create_ata(accounts: &[AccountInfo]) -> ProgramResult {
+    let [payer, wallet, ata, mint, system_program, token_program, _associated_token_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    assert_signer(payer)?;
+
+    let token_account = create_ata_if_needed(CreateAtaParams {
+        payer,
+        ata,
+        wallet,
+        mint,
+        payer_signer_seeds: &[],
+        system_program,
+        token_program,
+    })?;
+
+    if token_account.mint() != mint.key() || token_account.owner() != wallet.key() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    set_return_data(&token_account.amount().to_le_bytes());
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    cpi::invoke,
+    instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
+    ProgramResult,
+};
+use pinocchio_mpl_utils::{
+    assert_signer,
+    token::{create_mint_pda, CreateMintPdaParams, ExtensionType},
+};
+
+/// Token-2022 `InitializeMintCloseAuthority` discriminator
+const INITIALIZE_MINT_CLOSE_AUTHORITY: u8 = 25;
+
+/// Creates the `["mint", payer]` PDA mint with `payer` as its mint authority.
+/// A second data byte of 1 also gives the mint a `MintCloseAuthority` extension
+/// with `payer` as the close authority.
+pub fn process_create_mint(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [payer, mint, system_program, token_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let (decimals, close_authority) = match data {
+        [decimals] => (*decimals, false),
+        [decimals, close_authority] => (*decimals, *close_authority == 1),
+        _ => return Err(ProgramError::InvalidInstructionData),
+    };
+
+    assert_signer(payer)?;
+
+    let initialize_close_authority = || {
+        let mut instruction_data = [0u8; 34];
+        instruction_data[0] = INITIALIZE_MINT_CLOSE_AUTHORITY;
+        instruction_data[1] = 1;
+        instruction_data[2..].copy_from_slice(payer.key());
+
+        invoke(
+            &Instruction {
+                program_id: token_program.key(),
+                accounts: &[AccountMeta::writable(mint.key())],
+                data: &instruction_data,
+            },
+            &[mint],
+        )
+    };
+
+    let (extensions, initialize_extensions): (
+        &[ExtensionType],
+        Option<&dyn Fn() -> ProgramResult>,
+    ) = if close_authority {
+        (
+            &[ExtensionType::MintCloseAuthority],
+            Some(&initialize_close_authority),
+        )
+    } else {
+        (&[], None)
+    };
+
+    create_mint_pda(CreateMintPdaParams {
+        program_id: &crate::ID,
+        mint,
+        decimals,
+        mint_authority: payer.key(),
+        freeze_authority: None,
+        extensions,
+        initialize_extensions,
+        payer,
+        signer_seeds: &[b"mint", payer.key().as_ref()],
+        system_program,
+        token_program,
+    })
+}
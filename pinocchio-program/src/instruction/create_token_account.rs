@@ -0,0 +1,25 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use pinocchio_mpl_utils::{
+    assert_signer,
+    token::{create_token_account_pda, CreateTokenAccountPdaParams},
+};
+
+/// Creates the `["token", mint, payer]` PDA token account of `mint`, owned by `payer`.
+pub fn process_create_token_account(accounts: &[AccountInfo]) -> ProgramResult {
+    let [payer, token_account, mint, system_program, token_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    assert_signer(payer)?;
+
+    create_token_account_pda(CreateTokenAccountPdaParams {
+        program_id: &crate::ID,
+        token_account,
+        mint,
+        owner: payer.key(),
+        payer,
+        signer_seeds: &[b"token", mint.key().as_ref(), payer.key().as_ref()],
+        system_program,
+        token_program,
+    })
+}
@@ -0,0 +1,56 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use pinocchio_mpl_utils::{
+    assert_signer,
+    token::{
+        spl_token_approve, spl_token_approve_checked, spl_token_revoke, TokenApproveCheckedParams,
+        TokenApproveParams, TokenRevokeParams,
+    },
+};
+
+/// Sets or clears the delegate of `source`. The first data byte picks the helper:
+/// 0 for Approve, 1 for ApproveChecked and 2 for Revoke, followed by the amount
+/// and the decimals.
+pub fn process_delegate(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [authority, mint, source, delegate, token_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if data.len() < 10 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    let amount = u64::from_le_bytes(data[1..9].try_into().unwrap());
+    let decimals = data[9];
+
+    assert_signer(authority)?;
+
+    match data[0] {
+        0 => spl_token_approve(TokenApproveParams {
+            source,
+            delegate,
+            amount,
+            authority,
+            multisig_signers: &[],
+            authority_signer_seeds: &[],
+            token_program,
+        }),
+        1 => spl_token_approve_checked(TokenApproveCheckedParams {
+            source,
+            mint,
+            delegate,
+            amount,
+            decimals,
+            authority,
+            multisig_signers: &[],
+            authority_signer_seeds: &[],
+            token_program,
+        }),
+        2 => spl_token_revoke(TokenRevokeParams {
+            source,
+            authority,
+            multisig_signers: &[],
+            authority_signer_seeds: &[],
+            token_program,
+        }),
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
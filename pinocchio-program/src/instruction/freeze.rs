@@ -0,0 +1,34 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use pinocchio_mpl_utils::{
+    assert_signer,
+    token::{spl_token_freeze, spl_token_thaw, TokenFreezeParams, TokenThawParams},
+};
+
+/// Freezes `token_account` when the first data byte is 1, thaws it when it's 0.
+pub fn process_freeze_thaw(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [freeze_authority, mint, token_account, token_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    assert_signer(freeze_authority)?;
+
+    match data.first() {
+        Some(1) => spl_token_freeze(TokenFreezeParams {
+            account: token_account,
+            mint,
+            freeze_authority,
+            multisig_signers: &[],
+            authority_signer_seeds: &[],
+            token_program,
+        }),
+        Some(0) => spl_token_thaw(TokenThawParams {
+            account: token_account,
+            mint,
+            freeze_authority,
+            multisig_signers: &[],
+            authority_signer_seeds: &[],
+            token_program,
+        }),
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
@@ -1,9 +1,54 @@
+pub mod assert_holder;
+pub mod assert_is_ata;
+pub mod create_account;
+pub mod create_ata;
+pub mod create_mint;
+pub mod create_token_account;
+pub mod delegate;
+pub mod freeze;
 pub mod initialize;
+pub mod multisig;
+pub mod set_authority;
+pub mod token_helpers;
+pub mod token_transfer;
+pub mod transfer_hook;
+pub mod wsol;
+pub use assert_holder::*;
+pub use assert_is_ata::*;
+pub use create_account::*;
+pub use create_ata::*;
+pub use create_mint::*;
+pub use create_token_account::*;
+pub use delegate::*;
+pub use freeze::*;
 pub use initialize::*;
+pub use multisig::*;
+pub use set_authority::*;
+pub use token_helpers::*;
+pub use token_transfer::*;
+pub use transfer_hook::*;
+pub use wsol::*;
 
 #[repr(u8)]
 pub enum PinocchioInstruction {
     Initialize,
+    TokenTransfer,
+    TokenHelpers,
+    AssertHolder,
+    AssertIsAta,
+    CreateAta,
+    CreateTokenAccount,
+    CreateSignedAccount,
+    CreateKeypairAccount,
+    PdaTransfer,
+    MultisigMintBurn,
+    TransferWithHook,
+    FreezeThaw,
+    Delegate,
+    SetAuthority,
+    WrapSol,
+    UnwrapSol,
+    CreateMint,
 }
 
 impl TryFrom<&u8> for PinocchioInstruction {
@@ -12,6 +57,23 @@ impl TryFrom<&u8> for PinocchioInstruction {
     fn try_from(value: &u8) -> Result<Self, Self::Error> {
         match value {
             0 => Ok(PinocchioInstruction::Initialize),
+            1 => Ok(PinocchioInstruction::TokenTransfer),
+            2 => Ok(PinocchioInstruction::TokenHelpers),
+            3 => Ok(PinocchioInstruction::AssertHolder),
+            4 => Ok(PinocchioInstruction::AssertIsAta),
+            5 => Ok(PinocchioInstruction::CreateAta),
+            6 => Ok(PinocchioInstruction::CreateTokenAccount),
+            7 => Ok(PinocchioInstruction::CreateSignedAccount),
+            8 => Ok(PinocchioInstruction::CreateKeypairAccount),
+            9 => Ok(PinocchioInstruction::PdaTransfer),
+            10 => Ok(PinocchioInstruction::MultisigMintBurn),
+            11 => Ok(PinocchioInstruction::TransferWithHook),
+            12 => Ok(PinocchioInstruction::FreezeThaw),
+            13 => Ok(PinocchioInstruction::Delegate),
+            14 => Ok(PinocchioInstruction::SetAuthority),
+            15 => Ok(PinocchioInstruction::WrapSol),
+            16 => Ok(PinocchioInstruction::UnwrapSol),
+            17 => Ok(PinocchioInstruction::CreateMint),
 
             _ => Err(pinocchio::program_error::ProgramError::InvalidInstructionData),
         }
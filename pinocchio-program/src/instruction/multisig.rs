@@ -0,0 +1,58 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use pinocchio_mpl_utils::token::{
+    assert_multisig_signers, spl_token_burn, spl_token_mint_to, TokenBurnParams, TokenMintToParams,
+    MAX_MULTISIG_SIGNERS,
+};
+
+/// Mints `2 * amount` to a token account and burns `amount` from it, with a token
+/// multisig as both the mint authority and the token account owner. The accounts
+/// after the token program are the multisig members signing, at least `m` of them.
+pub fn process_multisig_mint_burn(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [multisig, mint, token_account, token_program, signers @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if data.len() < 9 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    let amount = u64::from_le_bytes(data[0..8].try_into().unwrap());
+    let m = data[8];
+
+    if signers.len() > MAX_MULTISIG_SIGNERS {
+        return Err(ProgramError::InvalidArgument);
+    }
+    let mut multisig_signers = [multisig; MAX_MULTISIG_SIGNERS];
+    for (signer_ref, signer) in multisig_signers.iter_mut().zip(signers) {
+        *signer_ref = signer;
+    }
+    let multisig_signers = &multisig_signers[..signers.len()];
+
+    assert_multisig_signers(
+        multisig,
+        multisig_signers,
+        m,
+        ProgramError::MissingRequiredSignature,
+    )?;
+
+    spl_token_mint_to(TokenMintToParams {
+        mint,
+        destination: token_account,
+        amount: amount
+            .checked_mul(2)
+            .ok_or(ProgramError::ArithmeticOverflow)?,
+        authority: multisig,
+        multisig_signers,
+        authority_signer_seeds: &[],
+        token_program,
+    })?;
+
+    spl_token_burn(TokenBurnParams {
+        mint,
+        source: token_account,
+        amount,
+        authority: multisig,
+        multisig_signers,
+        authority_signer_seeds: &[],
+        token_program,
+    })
+}
@@ -0,0 +1,47 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use pinocchio_mpl_utils::{
+    assert_signer,
+    token::{spl_token_set_authority, AuthorityType, TokenSetAuthorityParams},
+};
+
+/// Hands one of the base authorities of a mint or token account over to the key
+/// in the data. The first data byte is the `AuthorityType`; leaving out the new
+/// authority removes it.
+pub fn process_set_authority(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [authority, account, token_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let (authority_type, new_authority) = match data {
+        [authority_type] => (*authority_type, None),
+        [authority_type, new_authority @ ..] => (
+            *authority_type,
+            Some(
+                new_authority
+                    .try_into()
+                    .map_err(|_| ProgramError::InvalidInstructionData)?,
+            ),
+        ),
+        [] => return Err(ProgramError::InvalidInstructionData),
+    };
+
+    let authority_type = match authority_type {
+        0 => AuthorityType::MintTokens,
+        1 => AuthorityType::FreezeAccount,
+        2 => AuthorityType::AccountOwner,
+        3 => AuthorityType::CloseAccount,
+        _ => return Err(ProgramError::InvalidInstructionData),
+    };
+
+    assert_signer(authority)?;
+
+    spl_token_set_authority(TokenSetAuthorityParams {
+        account,
+        authority,
+        authority_type,
+        new_authority,
+        multisig_signers: &[],
+        authority_signer_seeds: &[],
+        token_program,
+    })
+}
@@ -0,0 +1,143 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use pinocchio_mpl_utils::{
+    assert_signer,
+    token::{
+        spl_token_approve, spl_token_approve_checked, spl_token_burn, spl_token_close,
+        spl_token_freeze, spl_token_mint_to, spl_token_revoke, spl_token_set_authority,
+        spl_token_thaw, spl_token_transfer, spl_token_transfer_checked, AuthorityType,
+        TokenApproveCheckedParams, TokenApproveParams, TokenBurnParams, TokenCloseParams,
+        TokenFreezeParams, TokenMintToParams, TokenRevokeParams, TokenSetAuthorityParams,
+        TokenThawParams, TokenTransferCheckedParams, TokenTransferParams,
+    },
+};
+
+/// Runs every token CPI helper against whichever token program is passed in:
+/// mints `3 * amount` to the source, moves `amount` with Transfer and `amount`
+/// with TransferChecked, freezes and thaws the destination, approves and revokes
+/// a delegate on the source, burns the rest, closes the emptied source account and
+/// removes the mint authority.
+pub fn process_token_helpers(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [authority, mint, source, destination, token_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if data.len() < 9 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    let amount = u64::from_le_bytes(data[0..8].try_into().unwrap());
+    let decimals = data[8];
+
+    assert_signer(authority)?;
+
+    spl_token_mint_to(TokenMintToParams {
+        mint,
+        destination: source,
+        amount: amount
+            .checked_mul(3)
+            .ok_or(ProgramError::ArithmeticOverflow)?,
+        authority,
+        multisig_signers: &[],
+        authority_signer_seeds: &[],
+        token_program,
+    })?;
+
+    spl_token_transfer(TokenTransferParams {
+        source,
+        destination,
+        amount,
+        authority,
+        authority_signer_seeds: &[],
+        token_program,
+    })?;
+
+    spl_token_transfer_checked(TokenTransferCheckedParams {
+        source,
+        mint,
+        destination,
+        amount,
+        decimals,
+        authority,
+        authority_signer_seeds: &[],
+        token_program,
+    })?;
+
+    spl_token_freeze(TokenFreezeParams {
+        account: destination,
+        mint,
+        freeze_authority: authority,
+        multisig_signers: &[],
+        authority_signer_seeds: &[],
+        token_program,
+    })?;
+
+    spl_token_thaw(TokenThawParams {
+        account: destination,
+        mint,
+        freeze_authority: authority,
+        multisig_signers: &[],
+        authority_signer_seeds: &[],
+        token_program,
+    })?;
+
+    // the destination account is just an arbitrary delegate address here
+    spl_token_approve(TokenApproveParams {
+        source,
+        delegate: destination,
+        amount,
+        authority,
+        multisig_signers: &[],
+        authority_signer_seeds: &[],
+        token_program,
+    })?;
+
+    spl_token_approve_checked(TokenApproveCheckedParams {
+        source,
+        mint,
+        delegate: destination,
+        amount,
+        decimals,
+        authority,
+        multisig_signers: &[],
+        authority_signer_seeds: &[],
+        token_program,
+    })?;
+
+    spl_token_revoke(TokenRevokeParams {
+        source,
+        authority,
+        multisig_signers: &[],
+        authority_signer_seeds: &[],
+        token_program,
+    })?;
+
+    spl_token_burn(TokenBurnParams {
+        mint,
+        source,
+        amount,
+        authority,
+        multisig_signers: &[],
+        authority_signer_seeds: &[],
+        token_program,
+    })?;
+
+    spl_token_close(TokenCloseParams {
+        account: source,
+        destination: authority,
+        owner: authority,
+        multisig_signers: &[],
+        authority_signer_seeds: &[],
+        token_program,
+    })?;
+
+    spl_token_set_authority(TokenSetAuthorityParams {
+        account: mint,
+        authority,
+        authority_type: AuthorityType::MintTokens,
+        new_authority: None,
+        multisig_signers: &[],
+        authority_signer_seeds: &[],
+        token_program,
+    })?;
+
+    Ok(())
+}
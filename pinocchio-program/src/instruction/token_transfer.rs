@@ -0,0 +1,78 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use pinocchio_mpl_utils::{
+    assert_signer,
+    token::{
+        spl_token_transfer, spl_token_transfer_checked, TokenTransferCheckedParams,
+        TokenTransferParams,
+    },
+    SignerSeeds,
+};
+
+/// Moves `amount` from `source` to `destination` with Transfer, then another
+/// `amount` with TransferChecked.
+pub fn process_token_transfer(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [authority, mint, source, destination, token_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if data.len() < 9 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    let amount = u64::from_le_bytes(data[0..8].try_into().unwrap());
+    let decimals = data[8];
+
+    assert_signer(authority)?;
+
+    spl_token_transfer(TokenTransferParams {
+        source,
+        destination,
+        amount,
+        authority,
+        authority_signer_seeds: &[],
+        token_program,
+    })?;
+
+    spl_token_transfer_checked(TokenTransferCheckedParams {
+        source,
+        mint,
+        destination,
+        amount,
+        decimals,
+        authority,
+        authority_signer_seeds: &[],
+        token_program,
+    })
+}
+
+/// Moves `amount` out of `source`, owned by the `["vault_authority", mint]` PDA.
+/// The CPI is also signed by a `["co_signer"]` PDA listed first, so the
+/// authority isn't the first of the signer seed sets.
+pub fn process_pda_transfer(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [vault_authority, mint, source, destination, token_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if data.len() < 10 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    let amount = u64::from_le_bytes(data[0..8].try_into().unwrap());
+    let authority_bump = data[8];
+    let co_signer_bump = data[9];
+
+    let signer_seeds = [
+        SignerSeeds::new(&[b"co_signer"], Some(co_signer_bump))?,
+        SignerSeeds::new(
+            &[b"vault_authority", mint.key().as_ref()],
+            Some(authority_bump),
+        )?,
+    ];
+
+    spl_token_transfer(TokenTransferParams {
+        source,
+        destination,
+        amount,
+        authority: vault_authority,
+        authority_signer_seeds: &signer_seeds,
+        token_program,
+    })
+}
@@ -0,0 +1,61 @@
+use pinocchio::{
+    account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, ProgramResult,
+};
+use pinocchio_mpl_utils::{
+    assert_signer,
+    token::{spl_token_transfer_checked_with_hook, TokenTransferCheckedWithHookParams},
+};
+
+/// Transfers `amount` with TransferChecked, passing the accounts the mint's
+/// transfer hook requires. They're looked up in the accounts after the token program.
+pub fn process_transfer_with_hook(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [authority, mint, source, destination, token_program, remaining_accounts @ ..] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if data.len() < 9 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    let amount = u64::from_le_bytes(data[0..8].try_into().unwrap());
+    let decimals = data[8];
+
+    assert_signer(authority)?;
+
+    spl_token_transfer_checked_with_hook(TokenTransferCheckedWithHookParams {
+        source,
+        mint,
+        destination,
+        amount,
+        decimals,
+        authority,
+        remaining_accounts,
+        authority_signer_seeds: &[],
+        token_program,
+    })
+}
+
+/// Transfer hook `Execute` handler, for when this program is deployed as a mint's
+/// transfer hook. Counts the transfers in the first extra account, which must be
+/// owned by this program.
+pub fn process_execute(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [_source, _mint, _destination, _authority, _validation, counter, ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if counter.owner() != program_id {
+        return Err(ProgramError::IllegalOwner);
+    }
+
+    let mut data = counter.try_borrow_mut_data()?;
+    let count: &mut [u8; 8] = data
+        .get_mut(..8)
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or(ProgramError::InvalidAccountData)?;
+    *count = u64::from_le_bytes(*count)
+        .checked_add(1)
+        .ok_or(ProgramError::ArithmeticOverflow)?
+        .to_le_bytes();
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use pinocchio_mpl_utils::{
+    assert_signer,
+    token::{unwrap_sol, wrap_sol, TokenCloseParams, WrapSolParams},
+};
+
+/// Wraps `lamports` of the payer's SOL into its native token account.
+pub fn process_wrap_sol(accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [payer, native_token_account, token_program, _system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if data.len() < 8 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    let lamports = u64::from_le_bytes(data[0..8].try_into().unwrap());
+
+    assert_signer(payer)?;
+
+    wrap_sol(WrapSolParams {
+        payer,
+        native_token_account,
+        lamports,
+        payer_signer_seeds: &[],
+        token_program,
+    })
+}
+
+/// Closes the owner's native token account, unwrapping its SOL back to the owner.
+pub fn process_unwrap_sol(accounts: &[AccountInfo]) -> ProgramResult {
+    let [owner, native_token_account, token_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    assert_signer(owner)?;
+
+    unwrap_sol(TokenCloseParams {
+        account: native_token_account,
+        destination: owner,
+        owner,
+        multisig_signers: &[],
+        authority_signer_seeds: &[],
+        token_program,
+    })
+}
@@ -4,7 +4,15 @@ use pinocchio::{
     ProgramResult,
 };
 
-use crate::instruction::{process_intialize, PinocchioInstruction};
+use crate::instruction::{
+    process_assert_holder, process_assert_is_ata, process_create_ata,
+    process_create_keypair_account, process_create_mint, process_create_signed_account,
+    process_create_token_account, process_delegate, process_execute, process_freeze_thaw,
+    process_intialize, process_multisig_mint_burn, process_pda_transfer, process_set_authority,
+    process_token_helpers, process_token_transfer, process_transfer_with_hook, process_unwrap_sol,
+    process_wrap_sol, PinocchioInstruction,
+};
+use pinocchio_mpl_utils::token::EXECUTE_DISCRIMINATOR;
 
 mod instruction;
 mod state;
@@ -18,12 +26,36 @@ pub fn process_instruction(
     accounts: &[AccountInfo],
     instruction_data: &[u8],
 ) -> ProgramResult {
+    // invoked by Token-2022 when this program is a mint's transfer hook
+    if instruction_data.starts_with(&EXECUTE_DISCRIMINATOR) {
+        return process_execute(program_id, accounts);
+    }
+
     let (discriminator, data) = instruction_data
         .split_first()
         .ok_or(ProgramError::InvalidInstructionData)?;
 
     match PinocchioInstruction::try_from(discriminator)? {
         PinocchioInstruction::Initialize => process_intialize(accounts, data)?,
+        PinocchioInstruction::TokenTransfer => process_token_transfer(accounts, data)?,
+        PinocchioInstruction::TokenHelpers => process_token_helpers(accounts, data)?,
+        PinocchioInstruction::AssertHolder => process_assert_holder(accounts)?,
+        PinocchioInstruction::AssertIsAta => process_assert_is_ata(accounts)?,
+        PinocchioInstruction::CreateAta => process_create_ata(accounts)?,
+        PinocchioInstruction::CreateTokenAccount => process_create_token_account(accounts)?,
+        PinocchioInstruction::CreateSignedAccount => process_create_signed_account(accounts, data)?,
+        PinocchioInstruction::CreateKeypairAccount => {
+            process_create_keypair_account(accounts, data)?
+        }
+        PinocchioInstruction::PdaTransfer => process_pda_transfer(accounts, data)?,
+        PinocchioInstruction::MultisigMintBurn => process_multisig_mint_burn(accounts, data)?,
+        PinocchioInstruction::TransferWithHook => process_transfer_with_hook(accounts, data)?,
+        PinocchioInstruction::FreezeThaw => process_freeze_thaw(accounts, data)?,
+        PinocchioInstruction::Delegate => process_delegate(accounts, data)?,
+        PinocchioInstruction::SetAuthority => process_set_authority(accounts, data)?,
+        PinocchioInstruction::WrapSol => process_wrap_sol(accounts, data)?,
+        PinocchioInstruction::UnwrapSol => process_unwrap_sol(accounts)?,
+        PinocchioInstruction::CreateMint => process_create_mint(accounts, data)?,
         _ => return Err(ProgramError::InvalidInstructionData),
     }
     Ok(())
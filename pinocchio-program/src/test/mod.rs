@@ -4,30 +4,45 @@ mod tests {
 
     use std::io::Error;
 
-    use litesvm::LiteSVM;
+    use litesvm::{
+        types::{FailedTransactionMetadata, TransactionMetadata},
+        LiteSVM,
+    };
     use litesvm_token::{
         spl_token::{
             self,
             solana_program::{msg, rent::Rent, sysvar::SysvarId},
         },
-        CreateAssociatedTokenAccount, CreateAssociatedTokenAccountIdempotent, CreateMint, MintTo,
+        CreateAssociatedTokenAccount, CreateAssociatedTokenAccountIdempotent, CreateMint,
+        CreateMultisig, MintTo,
     };
 
     use pinocchio::pubkey::pubkey_eq;
-    use solana_instruction::{AccountMeta, Instruction};
+    use pinocchio_mpl_utils::token::EXECUTE_DISCRIMINATOR;
+    use solana_account::Account;
+    use solana_instruction::{error::InstructionError, AccountMeta, Instruction};
     use solana_keypair::Keypair;
     use solana_message::Message;
     use solana_native_token::LAMPORTS_PER_SOL;
     use solana_pubkey::Pubkey;
     use solana_signer::Signer;
     use solana_transaction::Transaction;
+    use solana_transaction_error::TransactionError;
     use spl_associated_token_account::solana_program::clock::Clock;
     use spl_associated_token_account::solana_program::program_pack::Pack;
+    use spl_token_2022::{
+        extension::{
+            mint_close_authority::MintCloseAuthority, BaseStateWithExtensions, ExtensionType,
+            StateWithExtensions,
+        },
+        state::{Account as TokenAccount2022, AccountState, Mint as Mint2022},
+    };
 
     use crate::{instruction::GlobalState, instruction::PinocchioInstruction};
 
     const PROGRAM_ID: Pubkey = Pubkey::new_from_array(crate::ID); //"CntDHuHyUa1sEyLEYoHbrYdzM2G4VeDHSdQjQXXdRh6E";
     const TOKEN_PROGRAM_ID: Pubkey = spl_token::ID;
+    const TOKEN_2022_PROGRAM_ID: Pubkey = spl_token_2022::ID;
     const ASSOCIATED_TOKEN_PROGRAM_ID: &str = "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL";
 
     fn program_id() -> Pubkey {
@@ -165,4 +180,1670 @@ mod tests {
         handle_create_global_state(&mut svm, &reusable_state);
         Ok(())
     }
+
+    pub fn handle_token_transfer(
+        svm: &mut LiteSVM,
+        reusable_state: &ReusableState,
+        token_program: Pubkey,
+    ) -> Result<(), Error> {
+        let payer = &reusable_state.admin;
+        let decimals = 6;
+        let amount = 1_000_000u64;
+
+        let mint = CreateMint::new(svm, payer)
+            .decimals(decimals)
+            .authority(&payer.pubkey())
+            .token_program_id(&token_program)
+            .send()
+            .unwrap();
+
+        let source = CreateAssociatedTokenAccount::new(svm, payer, &mint)
+            .owner(&payer.pubkey())
+            .token_program_id(&token_program)
+            .send()
+            .unwrap();
+
+        let receiver = Keypair::new();
+        let destination = CreateAssociatedTokenAccount::new(svm, payer, &mint)
+            .owner(&receiver.pubkey())
+            .token_program_id(&token_program)
+            .send()
+            .unwrap();
+
+        MintTo::new(svm, payer, &mint, &source, 3 * amount)
+            .token_program_id(&token_program)
+            .send()
+            .unwrap();
+
+        let ix_data = [
+            vec![PinocchioInstruction::TokenTransfer as u8],
+            amount.to_le_bytes().to_vec(),
+            vec![decimals],
+        ]
+        .concat();
+
+        let token_transfer_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new_readonly(mint, false),
+                AccountMeta::new(source, false),
+                AccountMeta::new(destination, false),
+                AccountMeta::new_readonly(token_program, false),
+            ],
+            data: ix_data,
+        };
+
+        let message = Message::new(&[token_transfer_ix], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[payer], message, svm.latest_blockhash());
+
+        let tx = svm.send_transaction(transaction).unwrap();
+
+        // Transfer and TransferChecked each moved `amount`
+        let source_from_svm = svm.get_account(&source).unwrap();
+        let parsed_source =
+            StateWithExtensions::<TokenAccount2022>::unpack(&source_from_svm.data).unwrap();
+        assert_eq!(parsed_source.base.amount, amount);
+
+        let destination_from_svm = svm.get_account(&destination).unwrap();
+        let parsed_destination =
+            StateWithExtensions::<TokenAccount2022>::unpack(&destination_from_svm.data).unwrap();
+        assert_eq!(parsed_destination.base.amount, 2 * amount);
+
+        msg!("\nToken transfer transaction sucessful");
+        msg!("CUs Consumed: {}", tx.compute_units_consumed);
+        Ok(())
+    }
+
+    #[test]
+    pub fn token_transfer_tokenkeg() -> Result<(), Error> {
+        let (mut svm, reusable_state) = setup();
+        handle_token_transfer(&mut svm, &reusable_state, TOKEN_PROGRAM_ID)
+    }
+
+    #[test]
+    pub fn token_transfer_token_2022() -> Result<(), Error> {
+        let (mut svm, reusable_state) = setup();
+        handle_token_transfer(&mut svm, &reusable_state, TOKEN_2022_PROGRAM_ID)
+    }
+
+    #[test]
+    pub fn token_transfer_rejects_unknown_token_program() -> Result<(), Error> {
+        let (mut svm, reusable_state) = setup();
+        let payer = &reusable_state.admin;
+
+        let ix_data = [
+            vec![PinocchioInstruction::TokenTransfer as u8],
+            1u64.to_le_bytes().to_vec(),
+            vec![6],
+        ]
+        .concat();
+
+        let token_transfer_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new_readonly(reusable_state.mint, false),
+                AccountMeta::new(reusable_state.treasury, false),
+                AccountMeta::new(reusable_state.treasury, false),
+                AccountMeta::new_readonly(Pubkey::new_unique(), false),
+            ],
+            data: ix_data,
+        };
+
+        let message = Message::new(&[token_transfer_ix], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[payer], message, svm.latest_blockhash());
+
+        let result = svm.send_transaction(transaction);
+        assert_eq!(
+            result.unwrap_err().err,
+            TransactionError::InstructionError(0, InstructionError::IncorrectProgramId)
+        );
+        Ok(())
+    }
+
+    pub fn handle_token_helpers(
+        svm: &mut LiteSVM,
+        reusable_state: &ReusableState,
+        token_program: Pubkey,
+    ) -> Result<(), Error> {
+        let payer = &reusable_state.admin;
+        let decimals = 6;
+        let amount = 1_000_000u64;
+
+        let mint = CreateMint::new(svm, payer)
+            .decimals(decimals)
+            .authority(&payer.pubkey())
+            .freeze_authority(&payer.pubkey())
+            .token_program_id(&token_program)
+            .send()
+            .unwrap();
+
+        let source = CreateAssociatedTokenAccount::new(svm, payer, &mint)
+            .owner(&payer.pubkey())
+            .token_program_id(&token_program)
+            .send()
+            .unwrap();
+
+        let receiver = Keypair::new();
+        let destination = CreateAssociatedTokenAccount::new(svm, payer, &mint)
+            .owner(&receiver.pubkey())
+            .token_program_id(&token_program)
+            .send()
+            .unwrap();
+
+        let ix_data = [
+            vec![PinocchioInstruction::TokenHelpers as u8],
+            amount.to_le_bytes().to_vec(),
+            vec![decimals],
+        ]
+        .concat();
+
+        let token_helpers_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new(mint, false),
+                AccountMeta::new(source, false),
+                AccountMeta::new(destination, false),
+                AccountMeta::new_readonly(token_program, false),
+            ],
+            data: ix_data,
+        };
+
+        let message = Message::new(&[token_helpers_ix], Some(&payer.pubkey()));
+        let recent_blockhash = svm.latest_blockhash();
+
+        let transaction = Transaction::new(&[&payer], message, recent_blockhash);
+
+        let tx = svm.send_transaction(transaction).unwrap();
+
+        // the source was emptied and closed back to the payer
+        assert!(svm
+            .get_account(&source)
+            .is_none_or(|account| account.lamports == 0));
+
+        let destination_from_svm = svm.get_account(&destination).unwrap();
+        assert_eq!(destination_from_svm.owner, token_program);
+        let parsed_destination =
+            StateWithExtensions::<TokenAccount2022>::unpack(&destination_from_svm.data).unwrap();
+        assert_eq!(parsed_destination.base.amount, 2 * amount);
+        // frozen and thawed again
+        assert_eq!(parsed_destination.base.state, AccountState::Initialized);
+
+        // the mint authority was removed
+        let mint_from_svm = svm.get_account(&mint).unwrap();
+        let parsed_mint = StateWithExtensions::<Mint2022>::unpack(&mint_from_svm.data).unwrap();
+        assert!(parsed_mint.base.mint_authority.is_none());
+
+        msg!("\nToken helpers transaction sucessful");
+        msg!("CUs Consumed: {}", tx.compute_units_consumed);
+        Ok(())
+    }
+
+    #[test]
+    pub fn token_helpers_token_2022() -> Result<(), Error> {
+        let (mut svm, reusable_state) = setup();
+        handle_token_helpers(&mut svm, &reusable_state, TOKEN_2022_PROGRAM_ID)
+    }
+
+    #[test]
+    pub fn token_helpers_tokenkeg() -> Result<(), Error> {
+        let (mut svm, reusable_state) = setup();
+        handle_token_helpers(&mut svm, &reusable_state, TOKEN_PROGRAM_ID)
+    }
+
+    fn send_assert_holder(
+        svm: &mut LiteSVM,
+        payer: &Keypair,
+        token_account: Pubkey,
+        owner: Pubkey,
+        mint: Pubkey,
+    ) -> Result<TransactionMetadata, Box<FailedTransactionMetadata>> {
+        let assert_holder_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new_readonly(token_account, false),
+                AccountMeta::new_readonly(owner, false),
+                AccountMeta::new_readonly(mint, false),
+            ],
+            data: vec![PinocchioInstruction::AssertHolder as u8],
+        };
+
+        let message = Message::new(&[assert_holder_ix], Some(&payer.pubkey()));
+        let recent_blockhash = svm.latest_blockhash();
+        let transaction = Transaction::new(&[payer], message, recent_blockhash);
+
+        svm.send_transaction(transaction).map_err(Box::new)
+    }
+
+    #[test]
+    pub fn assert_holder_accepts_token_account() -> Result<(), Error> {
+        let (mut svm, reusable_state) = setup();
+        let payer = &reusable_state.admin;
+
+        MintTo::new(
+            &mut svm,
+            payer,
+            &reusable_state.mint,
+            &reusable_state.treasury,
+            1,
+        )
+        .send()
+        .unwrap();
+
+        send_assert_holder(
+            &mut svm,
+            payer,
+            reusable_state.treasury,
+            payer.pubkey(),
+            reusable_state.mint,
+        )
+        .unwrap();
+        Ok(())
+    }
+
+    #[test]
+    pub fn assert_holder_rejects_spoofed_token_account() -> Result<(), Error> {
+        let (mut svm, reusable_state) = setup();
+        let payer = &reusable_state.admin;
+
+        MintTo::new(
+            &mut svm,
+            payer,
+            &reusable_state.mint,
+            &reusable_state.treasury,
+            1,
+        )
+        .send()
+        .unwrap();
+
+        // same bytes as a real token account, owned by another program
+        let mut spoofed_account = svm.get_account(&reusable_state.treasury).unwrap();
+        spoofed_account.owner = program_id();
+        let spoofed = Pubkey::new_unique();
+        svm.set_account(spoofed, spoofed_account).unwrap();
+
+        let result = send_assert_holder(
+            &mut svm,
+            payer,
+            spoofed,
+            payer.pubkey(),
+            reusable_state.mint,
+        );
+        assert!(result.is_err());
+
+        // a token program account of multisig length that starts with the same bytes
+        let mut multisig_shaped_account = svm.get_account(&reusable_state.treasury).unwrap();
+        multisig_shaped_account
+            .data
+            .resize(spl_token::state::Multisig::LEN, 0);
+        multisig_shaped_account.lamports =
+            svm.minimum_balance_for_rent_exemption(spl_token::state::Multisig::LEN);
+        let multisig_shaped = Pubkey::new_unique();
+        svm.set_account(multisig_shaped, multisig_shaped_account)
+            .unwrap();
+
+        let result = send_assert_holder(
+            &mut svm,
+            payer,
+            multisig_shaped,
+            payer.pubkey(),
+            reusable_state.mint,
+        );
+        assert!(result.is_err());
+        Ok(())
+    }
+
+    #[test]
+    pub fn assert_holder_returns_error_for_uninitialized_token_account() -> Result<(), Error> {
+        let (mut svm, reusable_state) = setup();
+        let payer = &reusable_state.admin;
+
+        // token account sized, but never initialized
+        let uninitialized = Pubkey::new_unique();
+        svm.set_account(
+            uninitialized,
+            Account {
+                lamports: svm.minimum_balance_for_rent_exemption(165),
+                data: vec![0; 165],
+                owner: TOKEN_PROGRAM_ID,
+                executable: false,
+                rent_epoch: 0,
+            },
+        )
+        .unwrap();
+
+        // the caller's error comes back instead of a panic
+        let result = send_assert_holder(
+            &mut svm,
+            payer,
+            uninitialized,
+            payer.pubkey(),
+            reusable_state.mint,
+        );
+        assert_eq!(
+            result.unwrap_err().err,
+            TransactionError::InstructionError(0, InstructionError::InvalidAccountData)
+        );
+        Ok(())
+    }
+
+    fn send_assert_is_ata(
+        svm: &mut LiteSVM,
+        payer: &Keypair,
+        wallet: Pubkey,
+        mint: Pubkey,
+        token_program: Pubkey,
+        ata: Pubkey,
+    ) -> Result<TransactionMetadata, Box<FailedTransactionMetadata>> {
+        let assert_is_ata_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new_readonly(wallet, false),
+                AccountMeta::new_readonly(mint, false),
+                AccountMeta::new_readonly(token_program, false),
+                AccountMeta::new_readonly(ata, false),
+            ],
+            data: vec![PinocchioInstruction::AssertIsAta as u8],
+        };
+
+        let message = Message::new(&[assert_is_ata_ix], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[payer], message, svm.latest_blockhash());
+
+        svm.send_transaction(transaction).map_err(Box::new)
+    }
+
+    #[test]
+    pub fn assert_is_ata_accepts_ata() -> Result<(), Error> {
+        let (mut svm, reusable_state) = setup();
+        let payer = &reusable_state.admin;
+
+        send_assert_is_ata(
+            &mut svm,
+            payer,
+            payer.pubkey(),
+            reusable_state.mint,
+            TOKEN_PROGRAM_ID,
+            reusable_state.treasury,
+        )
+        .unwrap();
+        Ok(())
+    }
+
+    #[test]
+    pub fn assert_is_ata_rejects_other_address() -> Result<(), Error> {
+        let (mut svm, reusable_state) = setup();
+        let payer = &reusable_state.admin;
+
+        // the ATA of another wallet
+        let result = send_assert_is_ata(
+            &mut svm,
+            payer,
+            Pubkey::new_unique(),
+            reusable_state.mint,
+            TOKEN_PROGRAM_ID,
+            reusable_state.treasury,
+        );
+        assert!(result.is_err());
+
+        // the same seeds under Token-2022 give another address
+        let result = send_assert_is_ata(
+            &mut svm,
+            payer,
+            payer.pubkey(),
+            reusable_state.mint,
+            TOKEN_2022_PROGRAM_ID,
+            reusable_state.treasury,
+        );
+        assert!(result.is_err());
+        Ok(())
+    }
+
+    fn send_create_ata(
+        svm: &mut LiteSVM,
+        reusable_state: &ReusableState,
+        wallet: Pubkey,
+        mint: Pubkey,
+        token_program: Pubkey,
+        ata: Pubkey,
+    ) -> Result<TransactionMetadata, Box<FailedTransactionMetadata>> {
+        let payer = &reusable_state.admin;
+
+        let create_ata_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new_readonly(wallet, false),
+                AccountMeta::new(ata, false),
+                AccountMeta::new_readonly(mint, false),
+                AccountMeta::new_readonly(reusable_state.system_program_id, false),
+                AccountMeta::new_readonly(token_program, false),
+                AccountMeta::new_readonly(reusable_state.ata_program_id, false),
+            ],
+            data: vec![PinocchioInstruction::CreateAta as u8],
+        };
+
+        let message = Message::new(&[create_ata_ix], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[payer], message, svm.latest_blockhash());
+
+        svm.send_transaction(transaction).map_err(Box::new)
+    }
+
+    pub fn handle_create_ata(
+        svm: &mut LiteSVM,
+        reusable_state: &ReusableState,
+        token_program: Pubkey,
+        mint: Pubkey,
+        ata_len: usize,
+    ) -> Result<(), Error> {
+        let payer = &reusable_state.admin;
+        let wallet = Pubkey::new_unique();
+        let (ata, _bump) = Pubkey::find_program_address(
+            &[wallet.as_ref(), token_program.as_ref(), mint.as_ref()],
+            &reusable_state.ata_program_id,
+        );
+
+        // the first call creates the ATA
+        let result =
+            send_create_ata(svm, reusable_state, wallet, mint, token_program, ata).unwrap();
+        assert_eq!(result.return_data.data, 0u64.to_le_bytes());
+
+        let ata_from_svm = svm.get_account(&ata).unwrap();
+        assert_eq!(ata_from_svm.owner, token_program);
+        assert_eq!(ata_from_svm.data.len(), ata_len);
+
+        let parsed_ata =
+            StateWithExtensions::<TokenAccount2022>::unpack(&ata_from_svm.data).unwrap();
+        assert_eq!(parsed_ata.base.mint, mint);
+        assert_eq!(parsed_ata.base.owner, wallet);
+        assert_eq!(parsed_ata.base.state, AccountState::Initialized);
+
+        let amount = 1_000;
+        MintTo::new(svm, payer, &mint, &ata, amount)
+            .token_program_id(&token_program)
+            .send()
+            .unwrap();
+
+        // the second call leaves the existing ATA untouched and returns it
+        svm.expire_blockhash();
+        let result =
+            send_create_ata(svm, reusable_state, wallet, mint, token_program, ata).unwrap();
+        assert_eq!(result.return_data.data, amount.to_le_bytes());
+
+        let ata_from_svm = svm.get_account(&ata).unwrap();
+        assert_eq!(ata_from_svm.data.len(), ata_len);
+        let parsed_ata =
+            StateWithExtensions::<TokenAccount2022>::unpack(&ata_from_svm.data).unwrap();
+        assert_eq!(parsed_ata.base.owner, wallet);
+        assert_eq!(parsed_ata.base.amount, amount);
+        Ok(())
+    }
+
+    #[test]
+    pub fn create_ata_if_needed_tokenkeg() -> Result<(), Error> {
+        let (mut svm, reusable_state) = setup();
+        let mint = reusable_state.mint;
+        handle_create_ata(
+            &mut svm,
+            &reusable_state,
+            TOKEN_PROGRAM_ID,
+            mint,
+            spl_token::state::Account::LEN,
+        )
+    }
+
+    #[test]
+    pub fn create_ata_if_needed_token_2022() -> Result<(), Error> {
+        let (mut svm, reusable_state) = setup();
+        let mint = CreateMint::new(&mut svm, &reusable_state.admin)
+            .authority(&reusable_state.admin.pubkey())
+            .token_program_id(&TOKEN_2022_PROGRAM_ID)
+            .send()
+            .unwrap();
+
+        // Token-2022 ATAs always carry the ImmutableOwner extension
+        handle_create_ata(
+            &mut svm,
+            &reusable_state,
+            TOKEN_2022_PROGRAM_ID,
+            mint,
+            ExtensionType::try_calculate_account_len::<TokenAccount2022>(&[
+                ExtensionType::ImmutableOwner,
+            ])
+            .unwrap(),
+        )
+    }
+
+    /// Creates a mint of `token_program` with the given extensions, all of them
+    /// initialized by `extension_ixs`
+    fn create_mint_with_extensions(
+        svm: &mut LiteSVM,
+        payer: &Keypair,
+        token_program: Pubkey,
+        extensions: &[ExtensionType],
+        extension_ixs: impl FnOnce(&Pubkey) -> Vec<Instruction>,
+    ) -> Pubkey {
+        let mint = Keypair::new();
+        let mint_len = ExtensionType::try_calculate_account_len::<Mint2022>(extensions).unwrap();
+
+        let mut ixs = vec![solana_system_interface::instruction::create_account(
+            &payer.pubkey(),
+            &mint.pubkey(),
+            svm.minimum_balance_for_rent_exemption(mint_len),
+            mint_len as u64,
+            &token_program,
+        )];
+        ixs.extend(extension_ixs(&mint.pubkey()));
+        ixs.push(
+            spl_token_2022::instruction::initialize_mint2(
+                &token_program,
+                &mint.pubkey(),
+                &payer.pubkey(),
+                None,
+                6,
+            )
+            .unwrap(),
+        );
+
+        let message = Message::new(&ixs, Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[payer, &mint], message, svm.latest_blockhash());
+        svm.send_transaction(transaction).unwrap();
+
+        mint.pubkey()
+    }
+
+    pub fn handle_create_token_account(
+        svm: &mut LiteSVM,
+        reusable_state: &ReusableState,
+        token_program: Pubkey,
+        mint: Pubkey,
+        account_extensions: &[ExtensionType],
+    ) -> Result<(), Error> {
+        let payer = &reusable_state.admin;
+
+        let (token_account, _bump) = Pubkey::find_program_address(
+            &[b"token".as_ref(), mint.as_ref(), payer.pubkey().as_ref()],
+            &PROGRAM_ID,
+        );
+
+        let create_token_account_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new(token_account, false),
+                AccountMeta::new_readonly(mint, false),
+                AccountMeta::new_readonly(reusable_state.system_program_id, false),
+                AccountMeta::new_readonly(token_program, false),
+            ],
+            data: vec![PinocchioInstruction::CreateTokenAccount as u8],
+        };
+
+        let message = Message::new(&[create_token_account_ix], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[payer], message, svm.latest_blockhash());
+        svm.send_transaction(transaction).unwrap();
+
+        let token_account_from_svm = svm.get_account(&token_account).unwrap();
+        assert_eq!(token_account_from_svm.owner, token_program);
+        assert_eq!(
+            token_account_from_svm.data.len(),
+            ExtensionType::try_calculate_account_len::<TokenAccount2022>(account_extensions)
+                .unwrap()
+        );
+
+        let parsed_token_account =
+            StateWithExtensions::<TokenAccount2022>::unpack(&token_account_from_svm.data).unwrap();
+        assert_eq!(parsed_token_account.base.mint, mint);
+        assert_eq!(parsed_token_account.base.owner, payer.pubkey());
+        assert_eq!(parsed_token_account.base.state, AccountState::Initialized);
+        assert_eq!(
+            parsed_token_account.get_extension_types().unwrap(),
+            account_extensions
+        );
+        Ok(())
+    }
+
+    #[test]
+    pub fn create_token_account_pda_tokenkeg() -> Result<(), Error> {
+        let (mut svm, reusable_state) = setup();
+        let mint = reusable_state.mint;
+        handle_create_token_account(&mut svm, &reusable_state, TOKEN_PROGRAM_ID, mint, &[])
+    }
+
+    #[test]
+    pub fn create_token_account_pda_token_2022() -> Result<(), Error> {
+        let (mut svm, reusable_state) = setup();
+        let mint = CreateMint::new(&mut svm, &reusable_state.admin)
+            .authority(&reusable_state.admin.pubkey())
+            .token_program_id(&TOKEN_2022_PROGRAM_ID)
+            .send()
+            .unwrap();
+        handle_create_token_account(&mut svm, &reusable_state, TOKEN_2022_PROGRAM_ID, mint, &[])
+    }
+
+    #[test]
+    pub fn create_token_account_pda_token_2022_with_extensions() -> Result<(), Error> {
+        let (mut svm, reusable_state) = setup();
+        let payer = &reusable_state.admin;
+
+        // token accounts of a mint with transfer fees need a TransferFeeAmount extension
+        let mint = create_mint_with_extensions(
+            &mut svm,
+            payer,
+            TOKEN_2022_PROGRAM_ID,
+            &[ExtensionType::TransferFeeConfig],
+            |mint| {
+                vec![
+                    spl_token_2022::extension::transfer_fee::instruction::initialize_transfer_fee_config(
+                        &TOKEN_2022_PROGRAM_ID,
+                        mint,
+                        None,
+                        None,
+                        100,
+                        1_000,
+                    )
+                    .unwrap(),
+                ]
+            },
+        );
+
+        handle_create_token_account(
+            &mut svm,
+            &reusable_state,
+            TOKEN_2022_PROGRAM_ID,
+            mint,
+            &[ExtensionType::TransferFeeAmount],
+        )
+    }
+
+    fn assert_created_account(svm: &LiteSVM, new_account: &Pubkey, owner: &Pubkey, size: usize) {
+        let account = svm.get_account(new_account).unwrap();
+        assert_eq!(account.owner, *owner);
+        assert_eq!(account.data, vec![0; size]);
+        assert_eq!(
+            account.lamports,
+            svm.minimum_balance_for_rent_exemption(size)
+        );
+    }
+
+    fn send_create_signed_account(
+        svm: &mut LiteSVM,
+        reusable_state: &ReusableState,
+        new_account: Pubkey,
+        owner: Pubkey,
+        bump: u8,
+        size: usize,
+    ) -> Result<TransactionMetadata, Box<FailedTransactionMetadata>> {
+        let payer = &reusable_state.admin;
+
+        let create_account_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new(new_account, false),
+                AccountMeta::new_readonly(owner, false),
+                AccountMeta::new_readonly(reusable_state.system_program_id, false),
+            ],
+            data: [
+                vec![PinocchioInstruction::CreateSignedAccount as u8, bump],
+                (size as u64).to_le_bytes().to_vec(),
+            ]
+            .concat(),
+        };
+
+        let message = Message::new(&[create_account_ix], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[payer], message, svm.latest_blockhash());
+
+        svm.send_transaction(transaction).map_err(Box::new)
+    }
+
+    #[test]
+    pub fn create_signed_account_with_foreign_owner() -> Result<(), Error> {
+        let (mut svm, reusable_state) = setup();
+        let payer = reusable_state.admin.pubkey();
+        let (new_account, bump) =
+            Pubkey::find_program_address(&[b"account".as_ref(), payer.as_ref()], &PROGRAM_ID);
+        let owner = Pubkey::new_unique();
+        let size = 64;
+
+        send_create_signed_account(&mut svm, &reusable_state, new_account, owner, bump, size)
+            .unwrap();
+
+        assert_created_account(&svm, &new_account, &owner, size);
+        Ok(())
+    }
+
+    #[test]
+    pub fn create_signed_account_rejects_wrong_bump() -> Result<(), Error> {
+        let (mut svm, reusable_state) = setup();
+        let payer = reusable_state.admin.pubkey();
+        let (new_account, bump) =
+            Pubkey::find_program_address(&[b"account".as_ref(), payer.as_ref()], &PROGRAM_ID);
+
+        // the seeds with another bump don't sign for `new_account`
+        let result = send_create_signed_account(
+            &mut svm,
+            &reusable_state,
+            new_account,
+            Pubkey::new_unique(),
+            bump.wrapping_sub(1),
+            64,
+        );
+        assert!(result.is_err());
+        assert!(svm.get_account(&new_account).is_none());
+        Ok(())
+    }
+
+    fn send_create_keypair_account(
+        svm: &mut LiteSVM,
+        reusable_state: &ReusableState,
+        new_account: &Keypair,
+        new_account_signs: bool,
+        size: usize,
+    ) -> Result<TransactionMetadata, Box<FailedTransactionMetadata>> {
+        let payer = &reusable_state.admin;
+
+        let create_account_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new(new_account.pubkey(), new_account_signs),
+                AccountMeta::new_readonly(reusable_state.system_program_id, false),
+            ],
+            data: [
+                vec![PinocchioInstruction::CreateKeypairAccount as u8],
+                (size as u64).to_le_bytes().to_vec(),
+            ]
+            .concat(),
+        };
+
+        let message = Message::new(&[create_account_ix], Some(&payer.pubkey()));
+        let transaction = if new_account_signs {
+            Transaction::new(&[payer, new_account], message, svm.latest_blockhash())
+        } else {
+            Transaction::new(&[payer], message, svm.latest_blockhash())
+        };
+
+        svm.send_transaction(transaction).map_err(Box::new)
+    }
+
+    #[test]
+    pub fn create_keypair_account() -> Result<(), Error> {
+        let (mut svm, reusable_state) = setup();
+        let new_account = Keypair::new();
+        let size = 64;
+
+        send_create_keypair_account(&mut svm, &reusable_state, &new_account, true, size).unwrap();
+
+        assert_created_account(&svm, &new_account.pubkey(), &PROGRAM_ID, size);
+        Ok(())
+    }
+
+    #[test]
+    pub fn create_prefunded_keypair_account() -> Result<(), Error> {
+        let (mut svm, reusable_state) = setup();
+        let new_account = Keypair::new();
+        let size = 64;
+
+        // lamports already on the account rule out CreateAccount, so the helper
+        // tops it up and goes through Transfer, Allocate and Assign
+        svm.airdrop(&new_account.pubkey(), 1_000).unwrap();
+
+        let result =
+            send_create_keypair_account(&mut svm, &reusable_state, &new_account, true, size)
+                .unwrap();
+        assert!(result
+            .logs
+            .iter()
+            .any(|log| log.contains("Assign the account to the owning program")));
+
+        assert_created_account(&svm, &new_account.pubkey(), &PROGRAM_ID, size);
+        Ok(())
+    }
+
+    #[test]
+    pub fn create_keypair_account_requires_signature() -> Result<(), Error> {
+        let (mut svm, reusable_state) = setup();
+        let new_account = Keypair::new();
+
+        let result =
+            send_create_keypair_account(&mut svm, &reusable_state, &new_account, false, 64);
+        assert_eq!(
+            result.unwrap_err().err,
+            TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+        );
+        assert!(svm.get_account(&new_account.pubkey()).is_none());
+        Ok(())
+    }
+
+    pub fn handle_pda_transfer(
+        svm: &mut LiteSVM,
+        reusable_state: &ReusableState,
+        token_program: Pubkey,
+    ) -> Result<(), Error> {
+        let payer = &reusable_state.admin;
+        let amount = 1_000_000u64;
+
+        let mint = CreateMint::new(svm, payer)
+            .decimals(6)
+            .authority(&payer.pubkey())
+            .token_program_id(&token_program)
+            .send()
+            .unwrap();
+
+        let (vault_authority, authority_bump) =
+            Pubkey::find_program_address(&[b"vault_authority", mint.as_ref()], &PROGRAM_ID);
+        let (_co_signer, co_signer_bump) =
+            Pubkey::find_program_address(&[b"co_signer"], &PROGRAM_ID);
+
+        let source = CreateAssociatedTokenAccount::new(svm, payer, &mint)
+            .owner(&vault_authority)
+            .token_program_id(&token_program)
+            .send()
+            .unwrap();
+
+        let destination = CreateAssociatedTokenAccount::new(svm, payer, &mint)
+            .owner(&payer.pubkey())
+            .token_program_id(&token_program)
+            .send()
+            .unwrap();
+
+        MintTo::new(svm, payer, &mint, &source, 3 * amount)
+            .token_program_id(&token_program)
+            .send()
+            .unwrap();
+
+        let ix_data = [
+            vec![PinocchioInstruction::PdaTransfer as u8],
+            amount.to_le_bytes().to_vec(),
+            vec![authority_bump, co_signer_bump],
+        ]
+        .concat();
+
+        let pda_transfer_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new_readonly(vault_authority, false),
+                AccountMeta::new_readonly(mint, false),
+                AccountMeta::new(source, false),
+                AccountMeta::new(destination, false),
+                AccountMeta::new_readonly(token_program, false),
+            ],
+            data: ix_data,
+        };
+
+        let message = Message::new(&[pda_transfer_ix], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[payer], message, svm.latest_blockhash());
+
+        let tx = svm.send_transaction(transaction).unwrap();
+
+        // the vault authority signed through the second of the two signer seed sets
+        let source_from_svm = svm.get_account(&source).unwrap();
+        let parsed_source =
+            StateWithExtensions::<TokenAccount2022>::unpack(&source_from_svm.data).unwrap();
+        assert_eq!(parsed_source.base.amount, 2 * amount);
+
+        let destination_from_svm = svm.get_account(&destination).unwrap();
+        let parsed_destination =
+            StateWithExtensions::<TokenAccount2022>::unpack(&destination_from_svm.data).unwrap();
+        assert_eq!(parsed_destination.base.amount, amount);
+
+        msg!("\nPDA transfer transaction sucessful");
+        msg!("CUs Consumed: {}", tx.compute_units_consumed);
+        Ok(())
+    }
+
+    #[test]
+    pub fn pda_transfer_with_several_signers_tokenkeg() -> Result<(), Error> {
+        let (mut svm, reusable_state) = setup();
+        handle_pda_transfer(&mut svm, &reusable_state, TOKEN_PROGRAM_ID)
+    }
+
+    #[test]
+    pub fn pda_transfer_with_several_signers_token_2022() -> Result<(), Error> {
+        let (mut svm, reusable_state) = setup();
+        handle_pda_transfer(&mut svm, &reusable_state, TOKEN_2022_PROGRAM_ID)
+    }
+
+    /// A 2-of-3 token multisig that is the mint authority of a new mint and the
+    /// owner of a token account for it
+    fn setup_multisig(
+        svm: &mut LiteSVM,
+        payer: &Keypair,
+        members: &[Keypair; 3],
+        token_program: Pubkey,
+    ) -> (Pubkey, Pubkey, Pubkey) {
+        let member_keys: Vec<Pubkey> = members.iter().map(|member| member.pubkey()).collect();
+        let member_refs: Vec<&Pubkey> = member_keys.iter().collect();
+        let multisig = CreateMultisig::new(svm, payer, &member_refs, 2)
+            .token_program_id(&token_program)
+            .send()
+            .unwrap();
+
+        let mint = CreateMint::new(svm, payer)
+            .decimals(6)
+            .authority(&multisig)
+            .token_program_id(&token_program)
+            .send()
+            .unwrap();
+
+        let token_account = CreateAssociatedTokenAccount::new(svm, payer, &mint)
+            .owner(&multisig)
+            .token_program_id(&token_program)
+            .send()
+            .unwrap();
+
+        (multisig, mint, token_account)
+    }
+
+    fn send_multisig_mint_burn(
+        svm: &mut LiteSVM,
+        payer: &Keypair,
+        (multisig, mint, token_account): (Pubkey, Pubkey, Pubkey),
+        token_program: Pubkey,
+        signers: &[&Keypair],
+        amount: u64,
+        m: u8,
+    ) -> Result<TransactionMetadata, Box<FailedTransactionMetadata>> {
+        let mut accounts = vec![
+            AccountMeta::new_readonly(multisig, false),
+            AccountMeta::new(mint, false),
+            AccountMeta::new(token_account, false),
+            AccountMeta::new_readonly(token_program, false),
+        ];
+        accounts.extend(
+            signers
+                .iter()
+                .map(|signer| AccountMeta::new_readonly(signer.pubkey(), true)),
+        );
+
+        let multisig_ix = Instruction {
+            program_id: program_id(),
+            accounts,
+            data: [
+                vec![PinocchioInstruction::MultisigMintBurn as u8],
+                amount.to_le_bytes().to_vec(),
+                vec![m],
+            ]
+            .concat(),
+        };
+
+        let mut transaction_signers = vec![payer];
+        transaction_signers.extend_from_slice(signers);
+
+        let message = Message::new(&[multisig_ix], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&transaction_signers, message, svm.latest_blockhash());
+
+        svm.send_transaction(transaction).map_err(Box::new)
+    }
+
+    pub fn handle_multisig_mint_burn(
+        svm: &mut LiteSVM,
+        reusable_state: &ReusableState,
+        token_program: Pubkey,
+    ) -> Result<(), Error> {
+        let payer = &reusable_state.admin;
+        let members = [Keypair::new(), Keypair::new(), Keypair::new()];
+        let accounts = setup_multisig(svm, payer, &members, token_program);
+        let amount = 1_000u64;
+
+        send_multisig_mint_burn(
+            svm,
+            payer,
+            accounts,
+            token_program,
+            &[&members[0], &members[2]],
+            amount,
+            2,
+        )
+        .unwrap();
+
+        let token_account_from_svm = svm.get_account(&accounts.2).unwrap();
+        let parsed_token_account =
+            StateWithExtensions::<TokenAccount2022>::unpack(&token_account_from_svm.data).unwrap();
+        assert_eq!(parsed_token_account.base.amount, amount);
+
+        let mint_from_svm = svm.get_account(&accounts.1).unwrap();
+        let parsed_mint = StateWithExtensions::<Mint2022>::unpack(&mint_from_svm.data).unwrap();
+        assert_eq!(parsed_mint.base.supply, amount);
+
+        // one signer is below the multisig threshold, for assert_multisig_signers...
+        let result = send_multisig_mint_burn(
+            svm,
+            payer,
+            accounts,
+            token_program,
+            &[&members[1]],
+            amount,
+            2,
+        );
+        assert!(result.is_err());
+
+        // ...and for the token program
+        let result = send_multisig_mint_burn(
+            svm,
+            payer,
+            accounts,
+            token_program,
+            &[&members[1]],
+            amount,
+            1,
+        );
+        assert!(result.is_err());
+
+        // a signer that isn't a member
+        let outsider = Keypair::new();
+        let result = send_multisig_mint_burn(
+            svm,
+            payer,
+            accounts,
+            token_program,
+            &[&members[1], &outsider],
+            amount,
+            1,
+        );
+        assert!(result.is_err());
+        Ok(())
+    }
+
+    #[test]
+    pub fn multisig_mint_burn_tokenkeg() -> Result<(), Error> {
+        let (mut svm, reusable_state) = setup();
+        handle_multisig_mint_burn(&mut svm, &reusable_state, TOKEN_PROGRAM_ID)
+    }
+
+    #[test]
+    pub fn multisig_mint_burn_token_2022() -> Result<(), Error> {
+        let (mut svm, reusable_state) = setup();
+        handle_multisig_mint_burn(&mut svm, &reusable_state, TOKEN_2022_PROGRAM_ID)
+    }
+
+    #[test]
+    pub fn transfer_checked_with_hook() -> Result<(), Error> {
+        let (mut svm, reusable_state) = setup();
+        let payer = &reusable_state.admin;
+        let decimals = 6;
+        let amount = 1_000u64;
+
+        // the same program, deployed again as the mint's transfer hook
+        let hook_program_id = Pubkey::new_unique();
+        svm.add_program(
+            hook_program_id,
+            include_bytes!("../../target/deploy/pinocchio_program.so"),
+        );
+
+        let mint = Keypair::new();
+        let mint_len =
+            ExtensionType::try_calculate_account_len::<Mint2022>(&[ExtensionType::TransferHook])
+                .unwrap();
+        let create_mint_ixs = [
+            solana_system_interface::instruction::create_account(
+                &payer.pubkey(),
+                &mint.pubkey(),
+                svm.minimum_balance_for_rent_exemption(mint_len),
+                mint_len as u64,
+                &TOKEN_2022_PROGRAM_ID,
+            ),
+            spl_token_2022::extension::transfer_hook::instruction::initialize(
+                &TOKEN_2022_PROGRAM_ID,
+                &mint.pubkey(),
+                Some(payer.pubkey()),
+                Some(hook_program_id),
+            )
+            .unwrap(),
+            spl_token_2022::instruction::initialize_mint2(
+                &TOKEN_2022_PROGRAM_ID,
+                &mint.pubkey(),
+                &payer.pubkey(),
+                None,
+                decimals,
+            )
+            .unwrap(),
+        ];
+        let message = Message::new(&create_mint_ixs, Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[payer, &mint], message, svm.latest_blockhash());
+        svm.send_transaction(transaction).unwrap();
+        let mint = mint.pubkey();
+
+        let source = CreateAssociatedTokenAccount::new(&mut svm, payer, &mint)
+            .owner(&payer.pubkey())
+            .token_program_id(&TOKEN_2022_PROGRAM_ID)
+            .send()
+            .unwrap();
+        let destination = CreateAssociatedTokenAccount::new(&mut svm, payer, &mint)
+            .owner(&Pubkey::new_unique())
+            .token_program_id(&TOKEN_2022_PROGRAM_ID)
+            .send()
+            .unwrap();
+        MintTo::new(&mut svm, payer, &mint, &source, amount)
+            .token_program_id(&TOKEN_2022_PROGRAM_ID)
+            .send()
+            .unwrap();
+
+        // the hook requires one writable extra account: the PDA of ["counter", mint]
+        let (counter, _) =
+            Pubkey::find_program_address(&[b"counter".as_ref(), mint.as_ref()], &hook_program_id);
+        let mut counter_meta = [0u8; 35];
+        counter_meta[0] = 1;
+        counter_meta[1..3].copy_from_slice(&[1, 7]);
+        counter_meta[3..10].copy_from_slice(b"counter");
+        counter_meta[10..12].copy_from_slice(&[3, 1]);
+        counter_meta[34] = 1;
+        let validation_data = [
+            EXECUTE_DISCRIMINATOR.to_vec(),
+            (4 + counter_meta.len() as u32).to_le_bytes().to_vec(),
+            1u32.to_le_bytes().to_vec(),
+            counter_meta.to_vec(),
+        ]
+        .concat();
+
+        let (validation, _) = Pubkey::find_program_address(
+            &[b"extra-account-metas".as_ref(), mint.as_ref()],
+            &hook_program_id,
+        );
+        for (address, data) in [(validation, validation_data), (counter, vec![0u8; 8])] {
+            let account = solana_account::Account {
+                lamports: svm.minimum_balance_for_rent_exemption(data.len()),
+                data,
+                owner: hook_program_id,
+                executable: false,
+                rent_epoch: 0,
+            };
+            svm.set_account(address, account).unwrap();
+        }
+
+        let transfer_ix = |extra_accounts: &[AccountMeta]| Instruction {
+            program_id: program_id(),
+            accounts: [
+                vec![
+                    AccountMeta::new_readonly(payer.pubkey(), true),
+                    AccountMeta::new_readonly(mint, false),
+                    AccountMeta::new(source, false),
+                    AccountMeta::new(destination, false),
+                    AccountMeta::new_readonly(TOKEN_2022_PROGRAM_ID, false),
+                ],
+                extra_accounts.to_vec(),
+            ]
+            .concat(),
+            data: [
+                vec![PinocchioInstruction::TransferWithHook as u8],
+                amount.to_le_bytes().to_vec(),
+                vec![decimals],
+            ]
+            .concat(),
+        };
+
+        // the counter is missing
+        let message = Message::new(
+            &[transfer_ix(&[
+                AccountMeta::new_readonly(hook_program_id, false),
+                AccountMeta::new_readonly(validation, false),
+            ])],
+            Some(&payer.pubkey()),
+        );
+        let transaction = Transaction::new(&[payer], message, svm.latest_blockhash());
+        assert!(svm.send_transaction(transaction).is_err());
+
+        let message = Message::new(
+            &[transfer_ix(&[
+                AccountMeta::new(counter, false),
+                AccountMeta::new_readonly(hook_program_id, false),
+                AccountMeta::new_readonly(validation, false),
+            ])],
+            Some(&payer.pubkey()),
+        );
+        let transaction = Transaction::new(&[payer], message, svm.latest_blockhash());
+        svm.send_transaction(transaction).unwrap();
+
+        let destination_from_svm = svm.get_account(&destination).unwrap();
+        let parsed_destination =
+            StateWithExtensions::<TokenAccount2022>::unpack(&destination_from_svm.data).unwrap();
+        assert_eq!(parsed_destination.base.amount, amount);
+
+        // the hook ran once
+        let counter_from_svm = svm.get_account(&counter).unwrap();
+        assert_eq!(counter_from_svm.data, 1u64.to_le_bytes());
+        Ok(())
+    }
+
+    fn send_freeze_thaw(
+        svm: &mut LiteSVM,
+        payer: &Keypair,
+        mint: Pubkey,
+        token_account: Pubkey,
+        token_program: Pubkey,
+        freeze: bool,
+    ) -> Result<TransactionMetadata, Box<FailedTransactionMetadata>> {
+        let freeze_thaw_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new_readonly(payer.pubkey(), true),
+                AccountMeta::new_readonly(mint, false),
+                AccountMeta::new(token_account, false),
+                AccountMeta::new_readonly(token_program, false),
+            ],
+            data: vec![PinocchioInstruction::FreezeThaw as u8, freeze as u8],
+        };
+
+        let message = Message::new(&[freeze_thaw_ix], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[payer], message, svm.latest_blockhash());
+
+        svm.send_transaction(transaction).map_err(Box::new)
+    }
+
+    fn token_account_state(svm: &LiteSVM, token_account: &Pubkey) -> AccountState {
+        let account = svm.get_account(token_account).unwrap();
+        StateWithExtensions::<TokenAccount2022>::unpack(&account.data)
+            .unwrap()
+            .base
+            .state
+    }
+
+    pub fn handle_freeze_thaw(
+        svm: &mut LiteSVM,
+        reusable_state: &ReusableState,
+        token_program: Pubkey,
+    ) -> Result<(), Error> {
+        let payer = &reusable_state.admin;
+
+        let mint = CreateMint::new(svm, payer)
+            .decimals(6)
+            .authority(&payer.pubkey())
+            .freeze_authority(&payer.pubkey())
+            .token_program_id(&token_program)
+            .send()
+            .unwrap();
+
+        let token_account = CreateAssociatedTokenAccount::new(svm, payer, &mint)
+            .owner(&Keypair::new().pubkey())
+            .token_program_id(&token_program)
+            .send()
+            .unwrap();
+
+        send_freeze_thaw(svm, payer, mint, token_account, token_program, true).unwrap();
+        assert_eq!(
+            token_account_state(svm, &token_account),
+            AccountState::Frozen
+        );
+        // a frozen account can't receive tokens
+        assert!(MintTo::new(svm, payer, &mint, &token_account, 1)
+            .token_program_id(&token_program)
+            .send()
+            .is_err());
+
+        send_freeze_thaw(svm, payer, mint, token_account, token_program, false).unwrap();
+        assert_eq!(
+            token_account_state(svm, &token_account),
+            AccountState::Initialized
+        );
+        MintTo::new(svm, payer, &mint, &token_account, 1)
+            .token_program_id(&token_program)
+            .send()
+            .unwrap();
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn freeze_thaw_tokenkeg() -> Result<(), Error> {
+        let (mut svm, reusable_state) = setup();
+        handle_freeze_thaw(&mut svm, &reusable_state, TOKEN_PROGRAM_ID)
+    }
+
+    #[test]
+    pub fn freeze_thaw_token_2022() -> Result<(), Error> {
+        let (mut svm, reusable_state) = setup();
+        handle_freeze_thaw(&mut svm, &reusable_state, TOKEN_2022_PROGRAM_ID)
+    }
+
+    fn send_delegate(
+        svm: &mut LiteSVM,
+        payer: &Keypair,
+        (mint, source, delegate): (Pubkey, Pubkey, Pubkey),
+        token_program: Pubkey,
+        helper: u8,
+        amount: u64,
+        decimals: u8,
+    ) -> Result<TransactionMetadata, Box<FailedTransactionMetadata>> {
+        let delegate_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new_readonly(payer.pubkey(), true),
+                AccountMeta::new_readonly(mint, false),
+                AccountMeta::new(source, false),
+                AccountMeta::new_readonly(delegate, false),
+                AccountMeta::new_readonly(token_program, false),
+            ],
+            data: [
+                vec![PinocchioInstruction::Delegate as u8, helper],
+                amount.to_le_bytes().to_vec(),
+                vec![decimals],
+            ]
+            .concat(),
+        };
+
+        let message = Message::new(&[delegate_ix], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[payer], message, svm.latest_blockhash());
+
+        svm.send_transaction(transaction).map_err(Box::new)
+    }
+
+    fn token_account_delegate(svm: &LiteSVM, token_account: &Pubkey) -> (Option<Pubkey>, u64) {
+        let account = svm.get_account(token_account).unwrap();
+        let parsed = StateWithExtensions::<TokenAccount2022>::unpack(&account.data).unwrap();
+        (parsed.base.delegate.into(), parsed.base.delegated_amount)
+    }
+
+    pub fn handle_delegate(
+        svm: &mut LiteSVM,
+        reusable_state: &ReusableState,
+        token_program: Pubkey,
+    ) -> Result<(), Error> {
+        let payer = &reusable_state.admin;
+        let decimals = 6;
+
+        let mint = CreateMint::new(svm, payer)
+            .decimals(decimals)
+            .authority(&payer.pubkey())
+            .token_program_id(&token_program)
+            .send()
+            .unwrap();
+
+        let source = CreateAssociatedTokenAccount::new(svm, payer, &mint)
+            .owner(&payer.pubkey())
+            .token_program_id(&token_program)
+            .send()
+            .unwrap();
+
+        let delegate = Pubkey::new_unique();
+        let accounts = (mint, source, delegate);
+
+        // Approve
+        send_delegate(svm, payer, accounts, token_program, 0, 10, decimals).unwrap();
+        assert_eq!(token_account_delegate(svm, &source), (Some(delegate), 10));
+
+        // ApproveChecked, which the token program rejects with the wrong decimals
+        assert!(send_delegate(svm, payer, accounts, token_program, 1, 20, decimals + 1).is_err());
+        send_delegate(svm, payer, accounts, token_program, 1, 20, decimals).unwrap();
+        assert_eq!(token_account_delegate(svm, &source), (Some(delegate), 20));
+
+        // Revoke
+        send_delegate(svm, payer, accounts, token_program, 2, 0, decimals).unwrap();
+        assert_eq!(token_account_delegate(svm, &source), (None, 0));
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn delegate_tokenkeg() -> Result<(), Error> {
+        let (mut svm, reusable_state) = setup();
+        handle_delegate(&mut svm, &reusable_state, TOKEN_PROGRAM_ID)
+    }
+
+    #[test]
+    pub fn delegate_token_2022() -> Result<(), Error> {
+        let (mut svm, reusable_state) = setup();
+        handle_delegate(&mut svm, &reusable_state, TOKEN_2022_PROGRAM_ID)
+    }
+
+    fn send_set_authority(
+        svm: &mut LiteSVM,
+        authority: &Keypair,
+        account: Pubkey,
+        token_program: Pubkey,
+        authority_type: u8,
+        new_authority: Option<Pubkey>,
+    ) -> Result<TransactionMetadata, Box<FailedTransactionMetadata>> {
+        let mut data = vec![PinocchioInstruction::SetAuthority as u8, authority_type];
+        if let Some(new_authority) = new_authority {
+            data.extend_from_slice(new_authority.as_ref());
+        }
+
+        let set_authority_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new_readonly(authority.pubkey(), true),
+                AccountMeta::new(account, false),
+                AccountMeta::new_readonly(token_program, false),
+            ],
+            data,
+        };
+
+        let message = Message::new(&[set_authority_ix], Some(&authority.pubkey()));
+        let transaction = Transaction::new(&[authority], message, svm.latest_blockhash());
+
+        svm.send_transaction(transaction).map_err(Box::new)
+    }
+
+    pub fn handle_set_authority(
+        svm: &mut LiteSVM,
+        reusable_state: &ReusableState,
+        token_program: Pubkey,
+    ) -> Result<(), Error> {
+        let payer = &reusable_state.admin;
+        let new_authority = Pubkey::new_unique();
+
+        let mint = CreateMint::new(svm, payer)
+            .decimals(6)
+            .authority(&payer.pubkey())
+            .freeze_authority(&payer.pubkey())
+            .token_program_id(&token_program)
+            .send()
+            .unwrap();
+
+        let token_account = CreateAssociatedTokenAccount::new(svm, payer, &mint)
+            .owner(&payer.pubkey())
+            .token_program_id(&token_program)
+            .send()
+            .unwrap();
+
+        // MintTokens is handed over, FreezeAccount removed
+        send_set_authority(svm, payer, mint, token_program, 0, Some(new_authority)).unwrap();
+        send_set_authority(svm, payer, mint, token_program, 1, None).unwrap();
+
+        let mint_from_svm = svm.get_account(&mint).unwrap();
+        let parsed_mint = StateWithExtensions::<Mint2022>::unpack(&mint_from_svm.data).unwrap();
+        assert_eq!(
+            Option::<Pubkey>::from(parsed_mint.base.mint_authority),
+            Some(new_authority)
+        );
+        assert!(parsed_mint.base.freeze_authority.is_none());
+
+        // the old mint authority can't take it back
+        assert!(send_set_authority(svm, payer, mint, token_program, 0, None).is_err());
+
+        // CloseAccount, then AccountOwner, on the token account
+        send_set_authority(
+            svm,
+            payer,
+            token_account,
+            token_program,
+            3,
+            Some(new_authority),
+        )
+        .unwrap();
+        send_set_authority(
+            svm,
+            payer,
+            token_account,
+            token_program,
+            2,
+            Some(new_authority),
+        )
+        .unwrap();
+
+        let account_from_svm = svm.get_account(&token_account).unwrap();
+        let parsed_account =
+            StateWithExtensions::<TokenAccount2022>::unpack(&account_from_svm.data).unwrap();
+        assert_eq!(parsed_account.base.owner, new_authority);
+        assert_eq!(
+            Option::<Pubkey>::from(parsed_account.base.close_authority),
+            Some(new_authority)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn set_authority_tokenkeg() -> Result<(), Error> {
+        let (mut svm, reusable_state) = setup();
+        handle_set_authority(&mut svm, &reusable_state, TOKEN_PROGRAM_ID)
+    }
+
+    #[test]
+    pub fn set_authority_token_2022() -> Result<(), Error> {
+        let (mut svm, reusable_state) = setup();
+        handle_set_authority(&mut svm, &reusable_state, TOKEN_2022_PROGRAM_ID)
+    }
+
+    #[test]
+    pub fn wrap_and_unwrap_sol() -> Result<(), Error> {
+        let (mut svm, reusable_state) = setup();
+        let payer = &reusable_state.admin;
+        let lamports = LAMPORTS_PER_SOL;
+
+        let native_mint = spl_token::native_mint::ID;
+        let native_token_account = CreateAssociatedTokenAccount::new(&mut svm, payer, &native_mint)
+            .owner(&payer.pubkey())
+            .send()
+            .unwrap();
+
+        let wrap_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new(native_token_account, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(reusable_state.system_program_id, false),
+            ],
+            data: [
+                vec![PinocchioInstruction::WrapSol as u8],
+                lamports.to_le_bytes().to_vec(),
+            ]
+            .concat(),
+        };
+
+        let message = Message::new(&[wrap_ix], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[payer], message, svm.latest_blockhash());
+        svm.send_transaction(transaction).unwrap();
+
+        let native_from_svm = svm.get_account(&native_token_account).unwrap();
+        let parsed_native = spl_token::state::Account::unpack(&native_from_svm.data).unwrap();
+        assert_eq!(parsed_native.amount, lamports);
+
+        let unwrap_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new(native_token_account, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            ],
+            data: vec![PinocchioInstruction::UnwrapSol as u8],
+        };
+
+        let balance_before = svm.get_balance(&payer.pubkey()).unwrap();
+
+        let message = Message::new(&[unwrap_ix], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[payer], message, svm.latest_blockhash());
+        svm.send_transaction(transaction).unwrap();
+
+        // the wrapped SOL and the rent came back, minus the fee
+        assert!(svm
+            .get_account(&native_token_account)
+            .is_none_or(|account| account.lamports == 0));
+        assert!(svm.get_balance(&payer.pubkey()).unwrap() > balance_before + lamports - 10_000);
+        Ok(())
+    }
+
+    fn send_create_mint(
+        svm: &mut LiteSVM,
+        reusable_state: &ReusableState,
+        token_program: Pubkey,
+        decimals: u8,
+        close_authority: bool,
+    ) -> Result<TransactionMetadata, Box<FailedTransactionMetadata>> {
+        let payer = &reusable_state.admin;
+        let mint = mint_pda(&payer.pubkey());
+
+        let create_mint_ix = Instruction {
+            program_id: program_id(),
+            accounts: vec![
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new(mint, false),
+                AccountMeta::new_readonly(reusable_state.system_program_id, false),
+                AccountMeta::new_readonly(token_program, false),
+            ],
+            data: vec![
+                PinocchioInstruction::CreateMint as u8,
+                decimals,
+                close_authority as u8,
+            ],
+        };
+
+        let message = Message::new(&[create_mint_ix], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[payer], message, svm.latest_blockhash());
+
+        svm.send_transaction(transaction).map_err(Box::new)
+    }
+
+    fn mint_pda(payer: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[b"mint".as_ref(), payer.as_ref()], &PROGRAM_ID).0
+    }
+
+    pub fn handle_create_mint(
+        svm: &mut LiteSVM,
+        reusable_state: &ReusableState,
+        token_program: Pubkey,
+    ) -> Result<(), Error> {
+        let payer = &reusable_state.admin;
+        let decimals = 9;
+
+        send_create_mint(svm, reusable_state, token_program, decimals, false).unwrap();
+
+        let mint_from_svm = svm.get_account(&mint_pda(&payer.pubkey())).unwrap();
+        assert_eq!(mint_from_svm.owner, token_program);
+        let parsed_mint = StateWithExtensions::<Mint2022>::unpack(&mint_from_svm.data).unwrap();
+        assert_eq!(parsed_mint.base.decimals, decimals);
+        assert_eq!(parsed_mint.base.mint_authority, Some(payer.pubkey()).into());
+        assert!(parsed_mint.base.freeze_authority.is_none());
+        Ok(())
+    }
+
+    #[test]
+    pub fn create_mint_pda_tokenkeg() -> Result<(), Error> {
+        let (mut svm, reusable_state) = setup();
+        handle_create_mint(&mut svm, &reusable_state, TOKEN_PROGRAM_ID)
+    }
+
+    #[test]
+    pub fn create_mint_pda_token_2022() -> Result<(), Error> {
+        let (mut svm, reusable_state) = setup();
+        handle_create_mint(&mut svm, &reusable_state, TOKEN_2022_PROGRAM_ID)
+    }
+
+    #[test]
+    pub fn create_mint_pda_with_close_authority() -> Result<(), Error> {
+        let (mut svm, reusable_state) = setup();
+        let payer = &reusable_state.admin;
+
+        // Tokenkeg mints can't carry extensions
+        let result = send_create_mint(&mut svm, &reusable_state, TOKEN_PROGRAM_ID, 9, true);
+        assert!(result.is_err());
+
+        send_create_mint(&mut svm, &reusable_state, TOKEN_2022_PROGRAM_ID, 9, true).unwrap();
+
+        let mint_from_svm = svm.get_account(&mint_pda(&payer.pubkey())).unwrap();
+        assert_eq!(mint_from_svm.owner, TOKEN_2022_PROGRAM_ID);
+        let parsed_mint = StateWithExtensions::<Mint2022>::unpack(&mint_from_svm.data).unwrap();
+        assert_eq!(
+            parsed_mint.get_extension_types().unwrap(),
+            vec![ExtensionType::MintCloseAuthority]
+        );
+        let close_authority = parsed_mint.get_extension::<MintCloseAuthority>().unwrap();
+        assert_eq!(
+            Option::<Pubkey>::from(close_authority.close_authority),
+            Some(payer.pubkey())
+        );
+        Ok(())
+    }
 }
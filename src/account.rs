@@ -1,10 +1,9 @@
-use core::array;
+use core::slice;
 
 use pinocchio::{
     account_info::AccountInfo,
-    instruction::{Seed, Signer},
     program_error::ProgramError,
-    pubkey::{Pubkey, MAX_SEEDS},
+    pubkey::Pubkey,
     sysvars::{rent::Rent, Sysvar},
     ProgramResult,
 };
@@ -12,6 +11,8 @@ use pinocchio::{
 #[allow(unused_imports)]
 use pinocchio_log::log;
 
+use crate::{assert_signer_seeds, SignerSeeds};
+
 #[allow(unused_imports)]
 use pinocchio_system::ID as SYSTEM_PROGRAM_ID;
 
@@ -20,7 +21,6 @@ use pinocchio_system::instructions::{Allocate, Assign, CreateAccount, Transfer};
 
 /// Create account almost from scratch, lifted from
 /// <https://github.com/solana-labs/solana-program-library/tree/master/associated-token-account/program/src/processor.rs#L51-L98>
-#[allow(unused_variables)]
 pub fn create_or_allocate_account_raw<'a>(
     program_id: Pubkey,
     new_account_info: &'a AccountInfo,
@@ -28,6 +28,61 @@ pub fn create_or_allocate_account_raw<'a>(
     payer_info: &'a AccountInfo,
     size: usize,
     signer_seeds: &[&[u8]],
+) -> ProgramResult {
+    create_or_allocate_account_with_owner(
+        &program_id,
+        &program_id,
+        new_account_info,
+        system_program_info,
+        payer_info,
+        size,
+        signer_seeds,
+    )
+}
+
+/// Same as [`create_or_allocate_account_raw`], but the new account is assigned to
+/// `owner` while the PDA is still derived from `signer_seeds` under `program_id`.
+pub(crate) fn create_or_allocate_account_with_owner<'a>(
+    program_id: &Pubkey,
+    owner: &Pubkey,
+    new_account_info: &'a AccountInfo,
+    system_program_info: &'a AccountInfo,
+    payer_info: &'a AccountInfo,
+    size: usize,
+    signer_seeds: &[&[u8]],
+) -> ProgramResult {
+    // leaves room for the bump in the 16 seeds, and the PDA search panics on a
+    // seed longer than MAX_SEED_LEN
+    assert_signer_seeds(signer_seeds, true)?;
+
+    let (_pda, bump) = pinocchio::pubkey::find_program_address(signer_seeds, program_id);
+
+    // adding the bump to the end of the seeds
+    let signer_seeds = SignerSeeds::new(signer_seeds, Some(bump))?;
+
+    create_or_allocate_account_signed(
+        owner,
+        new_account_info,
+        system_program_info,
+        payer_info,
+        size,
+        &signer_seeds,
+    )
+}
+
+/// Same as [`create_or_allocate_account_raw`], but skips the `find_program_address`
+/// search: `signer_seeds` must already include the PDA bump. The new account is
+/// assigned to `owner`, which doesn't have to be the calling program.
+///
+/// Empty `signer_seeds` means the new account is a keypair, which then has to be
+/// a signer of the transaction.
+pub fn create_or_allocate_account_signed<'a>(
+    owner: &Pubkey,
+    new_account_info: &'a AccountInfo,
+    _system_program_info: &'a AccountInfo,
+    payer_info: &'a AccountInfo,
+    size: usize,
+    signer_seeds: &SignerSeeds<'_>,
 ) -> ProgramResult {
     let rent = Rent::get()?;
     let required_lamports = rent
@@ -35,47 +90,30 @@ pub fn create_or_allocate_account_raw<'a>(
         .max(1)
         .saturating_sub(new_account_info.lamports());
 
-    if signer_seeds.len() >= MAX_SEEDS {
-        return Err(ProgramError::MaxSeedLengthExceeded);
-    } // safety check to prevent seeds longer than 16
-
-    let (_pda, bump) = pinocchio::pubkey::find_program_address(signer_seeds, &program_id);
-    let bump_binding = [bump];
-
-    // PRE-ALLOCATE STACK BUFFER - you see, you can't just pass a 2D array &[&[u8]] in pinocchio as seeds,
-    // so here's my approach since we don't know what seeds format the dev is passing to this helper function
-
-    // Solana limits PDAs to 16 seeds max. We make a buffer of 16 empty seeds.
-    // Seed::from(&[]) creates a lightweight empty seed.
-    let mut seed_buffer: [Seed; MAX_SEEDS] = array::from_fn(|_| Seed::from(&[]));
-
-    // COPY SEEDS IN A LOOP
-    // This is very cheap. It just copies pointers (references), not data.
-    for (i, raw_seed) in signer_seeds.iter().enumerate() {
-        seed_buffer[i] = Seed::from(*raw_seed);
-    }
-
-    let total_seeds = signer_seeds.len();
-
-    // adding the bump to the end of the seeds
-    seed_buffer[total_seeds] = Seed::from(&bump_binding);
+    let signer = signer_seeds.signer();
 
-    let active_seeds = &seed_buffer[0..=total_seeds];
+    // without seeds the new account is a keypair that already signed the transaction
+    let signers = if signer_seeds.is_empty() {
+        if !new_account_info.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+        &[][..]
+    } else {
+        slice::from_ref(&signer)
+    };
 
     // [ if lamports exists, do the transfer, allocate, then assign ] else just do the create account
 
     if new_account_info.lamports() == 0 && new_account_info.data_is_empty() {
-        let signer = Signer::from(active_seeds);
         CreateAccount {
             from: payer_info,
             lamports: required_lamports,
-            owner: &program_id,
+            owner,
             space: size as u64,
             to: new_account_info,
         }
-        .invoke_signed(&[signer])?;
+        .invoke_signed(signers)?;
     } else {
-        let signer = Signer::from(active_seeds);
         // 1. Transfer lamports
         if required_lamports > 0 {
             log!("Transfer {} lamports to the new account", required_lamports);
@@ -94,19 +132,38 @@ pub fn create_or_allocate_account_raw<'a>(
             account: new_account_info,
             space: size as u64,
         }
-        .invoke_signed(&[signer])?;
+        .invoke_signed(signers)?;
 
         // 3. Assign (Set Owner)
         log!("Assign the account to the owning program");
         Assign {
             account: new_account_info,
-            owner: &program_id,
+            owner,
         }
-        .invoke_signed(&[Signer::from(active_seeds)])?;
+        .invoke_signed(signers)?;
     }
     Ok(())
 }
 
+/// Create a keypair (non-PDA) account that signed the transaction, assigned to `owner`.
+/// Pre-funded accounts go through the same Transfer, Allocate, Assign path as PDAs.
+pub fn create_or_allocate_account_keypair<'a>(
+    owner: &Pubkey,
+    new_account_info: &'a AccountInfo,
+    system_program_info: &'a AccountInfo,
+    payer_info: &'a AccountInfo,
+    size: usize,
+) -> ProgramResult {
+    create_or_allocate_account_signed(
+        owner,
+        new_account_info,
+        system_program_info,
+        payer_info,
+        size,
+        &SignerSeeds::empty(),
+    )
+}
+
 /// Resize an account using resize
 pub fn resize_or_reallocate_account_raw<'a>(
     target_account: &'a AccountInfo,
@@ -208,3 +265,46 @@ pub fn close_account_raw<'a>(
     // }
     // src_account_info.resize(0).unwrap();
 }
+
+#[cfg(test)]
+mod tests {
+    use pinocchio::pubkey::{MAX_SEEDS, MAX_SEED_LEN};
+
+    use super::*;
+    use crate::{test_utils::TestAccount, MplUtilsError};
+
+    fn create_with_seeds(signer_seeds: &[&[u8]]) -> ProgramResult {
+        let mut new_account = TestAccount::new([1u8; 32], SYSTEM_PROGRAM_ID, &[]);
+        let mut system_program = TestAccount::new(SYSTEM_PROGRAM_ID, [0u8; 32], &[]);
+        let mut payer = TestAccount::with_signer([2u8; 32], SYSTEM_PROGRAM_ID, &[], true);
+
+        create_or_allocate_account_raw(
+            [3u8; 32],
+            &new_account.info(),
+            &system_program.info(),
+            &payer.info(),
+            8,
+            signer_seeds,
+        )
+    }
+
+    #[test]
+    fn rejects_seed_too_long_before_pda_search() {
+        let seed = [0u8; MAX_SEED_LEN + 1];
+
+        assert_eq!(
+            create_with_seeds(&[b"prefix", &seed]),
+            Err(MplUtilsError::SeedTooLong.into())
+        );
+    }
+
+    #[test]
+    fn rejects_too_many_seeds() {
+        let seeds: [&[u8]; MAX_SEEDS] = [b"seed"; MAX_SEEDS];
+
+        assert_eq!(
+            create_with_seeds(&seeds),
+            Err(MplUtilsError::TooManySeeds.into())
+        );
+    }
+}
@@ -20,6 +20,10 @@ pub trait IsInitialized {
 /// Trait for accounts that can be unpacked from account data  ...more like read from bytes than unpack
 pub trait Pack: Sized {
     const LEN: usize;
+
+    /// # Safety
+    ///
+    /// `bytes` must be at least `Self::LEN` long and hold a valid `Self` layout.
     unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &Self;
 }
 
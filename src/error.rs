@@ -0,0 +1,23 @@
+use pinocchio::program_error::ProgramError;
+
+/// Errors returned by the helpers in this crate, surfaced as `ProgramError::Custom`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum MplUtilsError {
+    /// More than `MAX_SEEDS` signer seeds were passed
+    TooManySeeds = 0,
+    /// A signer seed is longer than `MAX_SEED_LEN` bytes
+    SeedTooLong = 1,
+    /// More than `MAX_CPI_SIGNERS` PDA signers were passed to a CPI
+    TooManySigners = 2,
+    /// More than `MAX_MULTISIG_SIGNERS` multisig signers were passed
+    TooManyMultisigSigners = 3,
+    /// A transfer hook requires more than `MAX_EXTRA_ACCOUNT_METAS` extra accounts
+    TooManyExtraAccountMetas = 4,
+}
+
+impl From<MplUtilsError> for ProgramError {
+    fn from(error: MplUtilsError) -> Self {
+        ProgramError::Custom(error as u32)
+    }
+}
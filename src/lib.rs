@@ -1,10 +1,16 @@
 pub use account::*;
 pub use assertions::*;
+pub use error::*;
 pub use misc::*;
+pub use seeds::*;
 
 mod account;
 mod assertions;
+mod error;
 mod misc;
+mod seeds;
+#[cfg(test)]
+mod test_utils;
 
 // #[cfg(feature = "spl-token")]
 pub mod token;
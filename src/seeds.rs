@@ -0,0 +1,211 @@
+use core::{array, ops::Deref};
+
+use pinocchio::{
+    instruction::{Seed, Signer},
+    program_error::ProgramError,
+    pubkey::{MAX_SEEDS, MAX_SEED_LEN},
+    ProgramResult,
+};
+
+use crate::MplUtilsError;
+
+/// Maximum number of PDA signers the runtime accepts in a single CPI
+pub const MAX_CPI_SIGNERS: usize = 16;
+
+/// One byte slice for every possible bump, so a bump can be used as a seed
+/// without borrowing a `[bump]` array from the caller's stack.
+static BUMP_SEEDS: [[u8; 1]; 256] = {
+    let mut bumps = [[0u8; 1]; 256];
+    let mut i = 0;
+    while i < 256 {
+        bumps[i][0] = i as u8;
+        i += 1;
+    }
+    bumps
+};
+
+/// Asserts that `seeds` fit in a PDA signer: at most `MAX_SEEDS` seeds of at
+/// most `MAX_SEED_LEN` bytes each. `with_bump` keeps the last slot free for a
+/// bump that still has to be appended.
+pub fn assert_signer_seeds<S: Deref<Target = [u8]>>(seeds: &[S], with_bump: bool) -> ProgramResult {
+    if seeds.len() + usize::from(with_bump) > MAX_SEEDS {
+        return Err(MplUtilsError::TooManySeeds.into());
+    }
+
+    if seeds.iter().any(|seed| seed.len() > MAX_SEED_LEN) {
+        return Err(MplUtilsError::SeedTooLong.into());
+    }
+
+    Ok(())
+}
+
+/// The seeds of one PDA signer, kept in a fixed `MAX_SEEDS` stack buffer.
+///
+/// You can't just pass a 2D array `&[&[u8]]` to pinocchio as seeds, so this copies
+/// the seeds into `Seed`s once. This only copies pointers (references), not data.
+pub struct SignerSeeds<'a> {
+    seeds: [Seed<'a>; MAX_SEEDS],
+    len: usize,
+}
+
+impl<'a> SignerSeeds<'a> {
+    /// No seeds, for accounts that sign the transaction themselves (keypairs)
+    pub fn empty() -> Self {
+        Self {
+            seeds: array::from_fn(|_| Seed::from(&[])),
+            len: 0,
+        }
+    }
+
+    /// Builds the signer seeds from byte slices, appending `bump` when given
+    pub fn new(seeds: &[&'a [u8]], bump: Option<u8>) -> Result<Self, ProgramError> {
+        assert_signer_seeds(seeds, bump.is_some())?;
+
+        let mut signer_seeds = Self::empty();
+        for (slot, raw_seed) in signer_seeds.seeds.iter_mut().zip(seeds) {
+            *slot = Seed::from(*raw_seed);
+        }
+        signer_seeds.len = seeds.len();
+
+        if let Some(bump) = bump {
+            signer_seeds.seeds[signer_seeds.len] = Seed::from(&BUMP_SEEDS[bump as usize]);
+            signer_seeds.len += 1;
+        }
+
+        Ok(signer_seeds)
+    }
+
+    /// Builds the signer seeds from pinocchio `Seed`s, e.g. the output of `seeds!`
+    pub fn from_seeds(seeds: &[Seed<'a>]) -> Result<Self, ProgramError> {
+        assert_signer_seeds(seeds, false)?;
+
+        let mut signer_seeds = Self::empty();
+        signer_seeds.seeds[..seeds.len()].clone_from_slice(seeds);
+        signer_seeds.len = seeds.len();
+
+        Ok(signer_seeds)
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The `Signer` to pass to `invoke_signed`
+    pub fn signer(&self) -> Signer<'a, '_> {
+        Signer::from(&self.seeds[..self.len])
+    }
+}
+
+impl<'a> TryFrom<&[&'a [u8]]> for SignerSeeds<'a> {
+    type Error = ProgramError;
+
+    fn try_from(seeds: &[&'a [u8]]) -> Result<Self, Self::Error> {
+        Self::new(seeds, None)
+    }
+}
+
+/// Several PDA signers for one CPI, e.g. an authority PDA and a payer PDA.
+/// Derefs to the `&[Signer]` slice `invoke_signed` expects.
+pub struct PdaSigners<'a, 'b> {
+    signers: [Signer<'a, 'b>; MAX_CPI_SIGNERS],
+    len: usize,
+}
+
+impl<'a, 'b> PdaSigners<'a, 'b> {
+    pub fn new(signer_seeds: &'b [SignerSeeds<'a>]) -> Result<Self, ProgramError> {
+        if signer_seeds.len() > MAX_CPI_SIGNERS {
+            return Err(MplUtilsError::TooManySigners.into());
+        }
+
+        let mut signers: [Signer<'a, 'b>; MAX_CPI_SIGNERS] =
+            array::from_fn(|_| Signer::from(&[][..]));
+        for (slot, seeds) in signers.iter_mut().zip(signer_seeds) {
+            *slot = seeds.signer();
+        }
+
+        Ok(Self {
+            signers,
+            len: signer_seeds.len(),
+        })
+    }
+}
+
+impl<'a, 'b> Deref for PdaSigners<'a, 'b> {
+    type Target = [Signer<'a, 'b>];
+
+    fn deref(&self) -> &Self::Target {
+        &self.signers[..self.len]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn accepts_max_seeds() {
+        let seed = [7u8; MAX_SEED_LEN];
+        let seeds = [&seed[..]; MAX_SEEDS];
+        assert!(assert_signer_seeds(&seeds, false).is_ok());
+        assert_eq!(SignerSeeds::new(&seeds, None).unwrap().len(), MAX_SEEDS);
+    }
+
+    #[test]
+    fn rejects_too_many_seeds() {
+        let seeds = [&b"seed"[..]; MAX_SEEDS + 1];
+        assert_eq!(
+            SignerSeeds::new(&seeds, None).err(),
+            Some(MplUtilsError::TooManySeeds.into())
+        );
+    }
+
+    #[test]
+    fn rejects_bump_past_max_seeds() {
+        let seeds = [&b"seed"[..]; MAX_SEEDS];
+        assert_eq!(
+            SignerSeeds::new(&seeds, Some(255)).err(),
+            Some(MplUtilsError::TooManySeeds.into())
+        );
+    }
+
+    #[test]
+    fn keeps_room_for_bump() {
+        let seeds = [&b"seed"[..]; MAX_SEEDS];
+        assert_eq!(
+            assert_signer_seeds(&seeds, true),
+            Err(MplUtilsError::TooManySeeds.into())
+        );
+        assert!(assert_signer_seeds(&seeds[1..], true).is_ok());
+    }
+
+    #[test]
+    fn rejects_seed_too_long() {
+        let seed = [7u8; MAX_SEED_LEN + 1];
+        assert_eq!(
+            assert_signer_seeds(&[&b"seed"[..], &seed], false),
+            Err(MplUtilsError::SeedTooLong.into())
+        );
+    }
+
+    #[test]
+    fn appends_bump() {
+        let signer_seeds = SignerSeeds::new(&[b"vault"], Some(254)).unwrap();
+        assert_eq!(signer_seeds.len(), 2);
+        assert_eq!(&*signer_seeds.seeds[0], b"vault");
+        assert_eq!(&*signer_seeds.seeds[1], &[254]);
+    }
+
+    #[test]
+    fn collects_multiple_signers() {
+        let signer_seeds = [
+            SignerSeeds::new(&[b"authority"], Some(1)).unwrap(),
+            SignerSeeds::new(&[b"payer"], Some(2)).unwrap(),
+        ];
+        assert_eq!(PdaSigners::new(&signer_seeds).unwrap().len(), 2);
+    }
+}
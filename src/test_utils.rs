@@ -0,0 +1,90 @@
+//! `AccountInfo`s for unit tests, laid out the way the runtime serializes them.
+
+use core::mem::{size_of, MaybeUninit};
+
+use pinocchio::{
+    account_info::{AccountInfo, MAX_PERMITTED_DATA_INCREASE},
+    entrypoint::{deserialize, NON_DUP_MARKER},
+    pubkey::Pubkey,
+};
+use pinocchio_token::state::{Mint, TokenAccount};
+
+/// Serialized program input holding a single account
+pub(crate) struct TestAccount {
+    input: Vec<u64>,
+}
+
+impl TestAccount {
+    pub fn new(key: Pubkey, owner: Pubkey, data: &[u8]) -> Self {
+        Self::with_signer(key, owner, data, false)
+    }
+
+    pub fn with_signer(key: Pubkey, owner: Pubkey, data: &[u8], is_signer: bool) -> Self {
+        let mut bytes = Vec::new();
+        // number of accounts
+        bytes.extend_from_slice(&1u64.to_le_bytes());
+        bytes.extend_from_slice(&[NON_DUP_MARKER, is_signer as u8, 1, 0]);
+        bytes.extend_from_slice(&0u32.to_le_bytes());
+        bytes.extend_from_slice(&key);
+        bytes.extend_from_slice(&owner);
+        bytes.extend_from_slice(&1_000_000u64.to_le_bytes());
+        bytes.extend_from_slice(&(data.len() as u64).to_le_bytes());
+        bytes.extend_from_slice(data);
+        bytes.resize(bytes.len() + MAX_PERMITTED_DATA_INCREASE, 0);
+        bytes.resize(bytes.len().next_multiple_of(size_of::<u64>()), 0);
+        // rent epoch, instruction data length and program id
+        bytes.resize(bytes.len() + 2 * size_of::<u64>() + size_of::<Pubkey>(), 0);
+
+        let input = bytes
+            .chunks_exact(size_of::<u64>())
+            .map(|chunk| u64::from_le_bytes(chunk.try_into().unwrap()))
+            .collect();
+
+        Self { input }
+    }
+
+    /// The returned `AccountInfo` points into `self` and must not outlive it.
+    pub fn info(&mut self) -> AccountInfo {
+        let mut accounts = [const { MaybeUninit::<AccountInfo>::uninit() }; 1];
+        let (_, count, _) =
+            unsafe { deserialize(self.input.as_mut_ptr() as *mut u8, &mut accounts) };
+        assert_eq!(count, 1);
+
+        unsafe { accounts[0].assume_init_read() }
+    }
+}
+
+/// Serializes an `Option<Pubkey>` as a token program `COption`
+fn write_coption(data: &mut [u8], key: Option<&Pubkey>) {
+    if let Some(key) = key {
+        data[..4].copy_from_slice(&1u32.to_le_bytes());
+        data[4..36].copy_from_slice(key);
+    }
+}
+
+/// Data of an initialized base mint
+pub(crate) fn mint_data(
+    mint_authority: Option<&Pubkey>,
+    supply: u64,
+    decimals: u8,
+    freeze_authority: Option<&Pubkey>,
+) -> Vec<u8> {
+    let mut data = vec![0u8; Mint::LEN];
+    write_coption(&mut data[0..36], mint_authority);
+    data[36..44].copy_from_slice(&supply.to_le_bytes());
+    data[44] = decimals;
+    data[45] = 1;
+    write_coption(&mut data[46..82], freeze_authority);
+    data
+}
+
+/// Data of an initialized base token account
+pub(crate) fn token_account_data(mint: &Pubkey, owner: &Pubkey, amount: u64) -> Vec<u8> {
+    let mut data = vec![0u8; TokenAccount::LEN];
+    data[0..32].copy_from_slice(mint);
+    data[32..64].copy_from_slice(owner);
+    data[64..72].copy_from_slice(&amount.to_le_bytes());
+    // AccountState::Initialized
+    data[108] = 1;
+    data
+}
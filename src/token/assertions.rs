@@ -5,23 +5,31 @@ use pinocchio::{
     ProgramResult,
 };
 use pinocchio_pubkey::pubkey;
-use pinocchio_token::state::{AccountState as Account, TokenAccount};
+// the base account layout is the same for Tokenkeg and Token-2022
+use pinocchio_token::state::{Mint, TokenAccount};
 
-#[cfg(feature = "token-2022")]
-use pinocchio_token_2022::state::{AccountState as Account, TokenAccount};
-
-use crate::assert_initialized;
 use crate::assertions::{IsInitialized, Pack};
+use crate::token::{
+    get_associated_token_address, is_mint_data, is_token_account_data, mint_extensions,
+    ExtensionType, Multisig, MAX_MULTISIG_SIGNERS,
+};
+use crate::{assert_initialized, assert_owner_in, cmp_pubkeys};
 
 pub static SPL_TOKEN_PROGRAM_IDS: [Pubkey; 2] = [
     pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
     pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"),
 ];
 
-impl Pack for TokenAccount {
-    #[cfg(feature = "token-2022")]
-    const LEN: usize = Self::BASE_LEN;
+/// Mint extensions [`assert_mint_extensions`] rejects unless they're explicitly allowed:
+/// they let a third party move, lock or block holders' tokens.
+pub const RESTRICTED_MINT_EXTENSIONS: [ExtensionType; 4] = [
+    ExtensionType::PermanentDelegate,
+    ExtensionType::TransferHook,
+    ExtensionType::NonTransferable,
+    ExtensionType::Pausable,
+];
 
+impl Pack for TokenAccount {
     const LEN: usize = Self::LEN;
 
     unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &Self {
@@ -31,20 +39,127 @@ impl Pack for TokenAccount {
 
 impl IsInitialized for TokenAccount {
     fn is_initialized(&self) -> bool {
-        Self::is_initialized(&self)
+        Self::is_initialized(self)
+    }
+}
+
+impl Pack for Mint {
+    const LEN: usize = Self::LEN;
+
+    unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &Self {
+        Self::from_bytes_unchecked(bytes)
+    }
+}
+
+impl IsInitialized for Mint {
+    fn is_initialized(&self) -> bool {
+        Self::is_initialized(self)
     }
 }
 
 pub trait ToTokenAccount<'a> {
+    /// Panics if the account isn't an initialized token account, prefer
+    /// [`ToTokenAccount::try_to_token_account`] in program code.
     fn to_token_account(&'a self) -> Ref<'a, TokenAccount>;
+
+    /// Returns `error` if the account isn't an initialized token account owned
+    /// by one of the [`SPL_TOKEN_PROGRAM_IDS`]. Only the base token account length
+    /// or an extended Token-2022 token account are accepted.
+    fn try_to_token_account(
+        &'a self,
+        error: impl Into<ProgramError>,
+    ) -> Result<Ref<'a, TokenAccount>, ProgramError>;
+
+    /// Same as [`ToTokenAccount::try_to_token_account`] without the owner check:
+    /// any account whose bytes look like a token account passes, so only use it
+    /// when the owner was already checked.
+    fn try_to_token_account_unchecked(
+        &'a self,
+        error: impl Into<ProgramError>,
+    ) -> Result<Ref<'a, TokenAccount>, ProgramError>;
 }
 
 impl<'a> ToTokenAccount<'a> for AccountInfo {
     fn to_token_account(&'a self) -> Ref<'a, TokenAccount> {
-        assert_initialized::<TokenAccount>(&self, ProgramError::UninitializedAccount).unwrap()
+        self.try_to_token_account(ProgramError::UninitializedAccount)
+            .unwrap()
+    }
+
+    fn try_to_token_account(
+        &'a self,
+        error: impl Into<ProgramError>,
+    ) -> Result<Ref<'a, TokenAccount>, ProgramError> {
+        if !is_token_program_owned(self) {
+            return Err(error.into());
+        }
+
+        self.try_to_token_account_unchecked(error)
+    }
+
+    fn try_to_token_account_unchecked(
+        &'a self,
+        error: impl Into<ProgramError>,
+    ) -> Result<Ref<'a, TokenAccount>, ProgramError> {
+        // a multisig is longer than a token account and would otherwise load as one
+        if !is_token_account_data(&self.try_borrow_data()?) {
+            return Err(error.into());
+        }
+
+        assert_initialized::<TokenAccount>(self, error)
     }
 }
 
+pub trait ToMint<'a> {
+    /// Returns `error` if the account isn't an initialized mint owned by one
+    /// of the [`SPL_TOKEN_PROGRAM_IDS`]. Only the base mint length or an extended
+    /// Token-2022 mint are accepted.
+    fn try_to_mint(&'a self, error: impl Into<ProgramError>)
+        -> Result<Ref<'a, Mint>, ProgramError>;
+
+    /// Same as [`ToMint::try_to_mint`] without the owner check: any account whose
+    /// bytes look like a mint passes, so only use it when the owner was already checked.
+    fn try_to_mint_unchecked(
+        &'a self,
+        error: impl Into<ProgramError>,
+    ) -> Result<Ref<'a, Mint>, ProgramError>;
+}
+
+impl<'a> ToMint<'a> for AccountInfo {
+    fn try_to_mint(
+        &'a self,
+        error: impl Into<ProgramError>,
+    ) -> Result<Ref<'a, Mint>, ProgramError> {
+        if !is_token_program_owned(self) {
+            return Err(error.into());
+        }
+
+        self.try_to_mint_unchecked(error)
+    }
+
+    fn try_to_mint_unchecked(
+        &'a self,
+        error: impl Into<ProgramError>,
+    ) -> Result<Ref<'a, Mint>, ProgramError> {
+        // a token account is longer than a mint and would otherwise load as one
+        if !is_mint_data(&self.try_borrow_data()?) {
+            return Err(error.into());
+        }
+
+        assert_initialized::<Mint>(self, error)
+    }
+}
+
+/// Without this check, an account owned by any program could imitate the bytes
+/// of a token account or mint.
+fn is_token_program_owned(account: &AccountInfo) -> bool {
+    SPL_TOKEN_PROGRAM_IDS
+        .iter()
+        .any(|program_id| cmp_pubkeys(program_id, account.owner()))
+}
+
+/// Token CPIs are built for whichever token program is passed in, so one binary
+/// can handle both Tokenkeg and Token-2022 accounts. This checks that program is
+/// one of the two before invoking it.
 pub fn assert_token_program_matches_package(
     token_program_info: &AccountInfo,
     error: impl Into<ProgramError>,
@@ -59,6 +174,66 @@ pub fn assert_token_program_matches_package(
     }
 }
 
+/// Asserts that `ata_info` is the associated token account of `wallet_info` for
+/// `mint_info` under the given token program. Only the address is checked, so
+/// this can be used before the account is created.
+pub fn assert_is_ata(
+    wallet_info: &AccountInfo,
+    mint_info: &AccountInfo,
+    token_program_info: &AccountInfo,
+    ata_info: &AccountInfo,
+    error: impl Into<ProgramError> + Clone,
+) -> ProgramResult {
+    assert_token_program_matches_package(token_program_info, error.clone())?;
+
+    let ata =
+        get_associated_token_address(wallet_info.key(), mint_info.key(), token_program_info.key());
+
+    if !cmp_pubkeys(&ata, ata_info.key()) {
+        return Err(error.into());
+    }
+
+    Ok(())
+}
+
+/// Delegate requirement for [`assert_holder_with`]
+#[derive(Clone, Copy)]
+pub enum DelegateCheck<'a> {
+    /// the delegate is not checked
+    Any,
+    /// the token account must not have a delegate
+    Forbidden,
+    /// the token account must be delegated to `delegate` for at least `min_delegated_amount`
+    Required {
+        delegate: &'a Pubkey,
+        min_delegated_amount: u64,
+    },
+}
+
+/// Extra checks for [`assert_holder_with`]. The default matches [`assert_holder`].
+#[derive(Clone, Copy)]
+pub struct HolderChecks<'a> {
+    /// minimum amount the token account must hold
+    pub min_amount: u64,
+    /// whether a frozen token account is accepted
+    pub allow_frozen: bool,
+    /// delegate requirement
+    pub delegate: DelegateCheck<'a>,
+    /// whether a token account with a close authority set is accepted
+    pub allow_close_authority: bool,
+}
+
+impl Default for HolderChecks<'_> {
+    fn default() -> Self {
+        Self {
+            min_amount: 1,
+            allow_frozen: true,
+            delegate: DelegateCheck::Any,
+            allow_close_authority: true,
+        }
+    }
+}
+
 /// Asserts that
 /// * the given token account is initialized
 /// * it's owner matches the provided owner
@@ -70,7 +245,31 @@ pub fn assert_holder(
     mint_info: &AccountInfo,
     error: impl Into<ProgramError> + Clone,
 ) -> ProgramResult {
-    let token_account = token_account.to_token_account();
+    assert_holder_with(
+        token_account,
+        owner_info,
+        mint_info,
+        HolderChecks::default(),
+        error,
+    )
+}
+
+/// Asserts that
+/// * the given token account is initialized
+/// * it's owner matches the provided owner
+/// * it's mint matches the provided mint
+/// * it holds at least `checks.min_amount` tokens of the given mint
+/// * it isn't frozen, unless `checks.allow_frozen`
+/// * it's delegate satisfies `checks.delegate`
+/// * it has no close authority, unless `checks.allow_close_authority`.
+pub fn assert_holder_with(
+    token_account: &AccountInfo,
+    owner_info: &AccountInfo,
+    mint_info: &AccountInfo,
+    checks: HolderChecks<'_>,
+    error: impl Into<ProgramError> + Clone,
+) -> ProgramResult {
+    let token_account = token_account.try_to_token_account(error.clone())?;
 
     if token_account.owner() != owner_info.key() {
         return Err(error.into());
@@ -80,9 +279,589 @@ pub fn assert_holder(
         return Err(error.into());
     }
 
-    if token_account.amount() == 0 {
+    if token_account.amount() < checks.min_amount {
+        return Err(error.into());
+    }
+
+    if !checks.allow_frozen && token_account.is_frozen() {
+        return Err(error.into());
+    }
+
+    match checks.delegate {
+        DelegateCheck::Any => {}
+        DelegateCheck::Forbidden => {
+            if token_account.has_delegate() {
+                return Err(error.into());
+            }
+        }
+        DelegateCheck::Required {
+            delegate,
+            min_delegated_amount,
+        } => {
+            if token_account.delegate() != Some(delegate)
+                || token_account.delegated_amount() < min_delegated_amount
+            {
+                return Err(error.into());
+            }
+        }
+    }
+
+    if !checks.allow_close_authority && token_account.has_close_authority() {
         return Err(error.into());
     }
 
     Ok(())
 }
+
+/// Asserts that the given mint is initialized and its mint authority matches
+/// `authority`. Pass `None` to assert the supply is fixed (no mint authority).
+pub fn assert_mint_authority(
+    mint_info: &AccountInfo,
+    authority: Option<&Pubkey>,
+    error: impl Into<ProgramError> + Clone,
+) -> ProgramResult {
+    let mint = mint_info.try_to_mint(error.clone())?;
+
+    if mint.mint_authority() != authority {
+        return Err(error.into());
+    }
+
+    Ok(())
+}
+
+/// Asserts that the given mint is initialized and its freeze authority matches
+/// `authority`. Pass `None` to assert the mint has no freeze authority.
+pub fn assert_freeze_authority(
+    mint_info: &AccountInfo,
+    authority: Option<&Pubkey>,
+    error: impl Into<ProgramError> + Clone,
+) -> ProgramResult {
+    let mint = mint_info.try_to_mint(error.clone())?;
+
+    if mint.freeze_authority() != authority {
+        return Err(error.into());
+    }
+
+    Ok(())
+}
+
+/// Asserts that the given mint is initialized and has exactly `decimals` decimals.
+pub fn assert_decimals(
+    mint_info: &AccountInfo,
+    decimals: u8,
+    error: impl Into<ProgramError> + Clone,
+) -> ProgramResult {
+    let mint = mint_info.try_to_mint(error.clone())?;
+
+    if mint.decimals() != decimals {
+        return Err(error.into());
+    }
+
+    Ok(())
+}
+
+/// Asserts that the given mint is initialized and its supply is at least `supply`.
+pub fn assert_supply_at_least(
+    mint_info: &AccountInfo,
+    supply: u64,
+    error: impl Into<ProgramError> + Clone,
+) -> ProgramResult {
+    let mint = mint_info.try_to_mint(error.clone())?;
+
+    if mint.supply() < supply {
+        return Err(error.into());
+    }
+
+    Ok(())
+}
+
+/// Asserts that
+/// * the given multisig account is an initialized token multisig, exactly [`Multisig::LEN`] long
+/// * at least `m` of its members are among `signers` and signed the transaction.
+pub fn assert_multisig_signers(
+    multisig_info: &AccountInfo,
+    signers: &[&AccountInfo],
+    m: u8,
+    error: impl Into<ProgramError> + Clone,
+) -> ProgramResult {
+    assert_owner_in(multisig_info, &SPL_TOKEN_PROGRAM_IDS, error.clone())?;
+    // extended mints and token accounts can be longer than a multisig
+    if multisig_info.data_len() != Multisig::LEN {
+        return Err(error.into());
+    }
+    let multisig = assert_initialized::<Multisig>(multisig_info, error.clone())?;
+
+    // same matching as the token program: a member counts once, however many times it's passed
+    let mut matched = [false; MAX_MULTISIG_SIGNERS];
+    let mut num_signers = 0u8;
+    for signer in signers {
+        for (position, key) in multisig.signers().iter().enumerate() {
+            if cmp_pubkeys(key, signer.key()) && !matched[position] {
+                if !signer.is_signer() {
+                    return Err(error.into());
+                }
+                matched[position] = true;
+                num_signers += 1;
+            }
+        }
+    }
+
+    if num_signers < m {
+        return Err(error.into());
+    }
+
+    Ok(())
+}
+
+/// Asserts that
+/// * the given mint is an initialized mint owned by a token program
+/// * it carries none of the [`RESTRICTED_MINT_EXTENSIONS`] that aren't in `allowed`.
+///
+/// Any other extension, including types this crate doesn't know about, is accepted.
+pub fn assert_mint_extensions(
+    mint_info: &AccountInfo,
+    allowed: &[ExtensionType],
+    error: impl Into<ProgramError> + Clone,
+) -> ProgramResult {
+    drop(mint_info.try_to_mint(error.clone())?);
+
+    let data = mint_info.try_borrow_data()?;
+    for extension in mint_extensions(&data)? {
+        let Some(extension_type) = extension?.extension_type() else {
+            continue;
+        };
+
+        if RESTRICTED_MINT_EXTENSIONS.contains(&extension_type)
+            && !allowed.contains(&extension_type)
+        {
+            return Err(error.into());
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{mint_data, token_account_data, TestAccount};
+
+    const TOKENKEG: Pubkey = SPL_TOKEN_PROGRAM_IDS[0];
+
+    /// A token account whose mint bytes read as an initialized mint with no
+    /// mint authority, and whose owner bytes hold the supply, decimals and
+    /// freeze authority.
+    fn mint_shaped_token_account() -> TestAccount {
+        let mut owner = [0xffu8; 32];
+        // is_initialized
+        owner[13] = 1;
+
+        TestAccount::new(
+            [1u8; 32],
+            TOKENKEG,
+            &token_account_data(&[0u8; 32], &owner, 1),
+        )
+    }
+
+    #[test]
+    fn mint_loaders_reject_token_account() {
+        let mut account = mint_shaped_token_account();
+        let info = account.info();
+
+        assert!(info.try_to_mint(ProgramError::Custom(1)).is_err());
+        assert!(info.try_to_mint_unchecked(ProgramError::Custom(1)).is_err());
+    }
+
+    #[test]
+    fn mint_assertions_reject_token_account() {
+        let mut account = mint_shaped_token_account();
+        let info = account.info();
+        let error = ProgramError::Custom(1);
+
+        assert_eq!(assert_mint_authority(&info, None, error), Err(error));
+        assert_eq!(assert_freeze_authority(&info, None, error), Err(error));
+        assert_eq!(assert_decimals(&info, 255, error), Err(error));
+        assert_eq!(assert_supply_at_least(&info, 0, error), Err(error));
+        assert_eq!(assert_mint_extensions(&info, &[], error), Err(error));
+    }
+
+    #[test]
+    fn mint_loader_accepts_mint() {
+        let mut account = TestAccount::new([1u8; 32], TOKENKEG, &mint_data(None, 0, 6, None));
+        let info = account.info();
+
+        assert_eq!(
+            info.try_to_mint(ProgramError::Custom(1))
+                .unwrap()
+                .decimals(),
+            6
+        );
+    }
+
+    #[test]
+    fn mint_loader_rejects_foreign_owner() {
+        let mut account = TestAccount::new([1u8; 32], [9u8; 32], &mint_data(None, 0, 6, None));
+        let info = account.info();
+
+        assert!(info.try_to_mint(ProgramError::Custom(1)).is_err());
+        assert!(info.try_to_mint_unchecked(ProgramError::Custom(1)).is_ok());
+    }
+
+    #[test]
+    fn mint_assertions_forward_error_for_uninitialized_mint() {
+        let mut data = mint_data(None, 0, 6, None);
+        // is_initialized
+        data[45] = 0;
+        let mut account = TestAccount::new([1u8; 32], TOKENKEG, &data);
+        let info = account.info();
+        let error = ProgramError::Custom(7);
+
+        assert_eq!(assert_mint_authority(&info, None, error), Err(error));
+        assert_eq!(assert_freeze_authority(&info, None, error), Err(error));
+        assert_eq!(assert_decimals(&info, 6, error), Err(error));
+        assert_eq!(assert_supply_at_least(&info, 0, error), Err(error));
+    }
+
+    #[test]
+    fn mint_assertions() {
+        let authority = [3u8; 32];
+        let freeze_authority = [4u8; 32];
+        let mut account = TestAccount::new(
+            [1u8; 32],
+            TOKENKEG,
+            &mint_data(Some(&authority), 100, 6, Some(&freeze_authority)),
+        );
+        let info = account.info();
+        let error = ProgramError::Custom(7);
+
+        assert!(assert_mint_authority(&info, Some(&authority), error).is_ok());
+        assert_eq!(assert_mint_authority(&info, None, error), Err(error));
+        assert_eq!(
+            assert_mint_authority(&info, Some(&freeze_authority), error),
+            Err(error)
+        );
+
+        assert!(assert_freeze_authority(&info, Some(&freeze_authority), error).is_ok());
+        assert_eq!(assert_freeze_authority(&info, None, error), Err(error));
+
+        assert!(assert_decimals(&info, 6, error).is_ok());
+        assert_eq!(assert_decimals(&info, 9, error), Err(error));
+
+        assert!(assert_supply_at_least(&info, 100, error).is_ok());
+        assert_eq!(assert_supply_at_least(&info, 101, error), Err(error));
+    }
+
+    #[test]
+    fn mint_assertions_without_authorities() {
+        let mut account = TestAccount::new([1u8; 32], TOKENKEG, &mint_data(None, 0, 6, None));
+        let info = account.info();
+        let error = ProgramError::Custom(7);
+
+        assert!(assert_mint_authority(&info, None, error).is_ok());
+        assert_eq!(
+            assert_mint_authority(&info, Some(&[3u8; 32]), error),
+            Err(error)
+        );
+        assert!(assert_freeze_authority(&info, None, error).is_ok());
+        assert_eq!(
+            assert_freeze_authority(&info, Some(&[4u8; 32]), error),
+            Err(error)
+        );
+    }
+
+    /// Runs [`assert_holder_with`] on a token account of `owner` for `mint`
+    /// holding 10 tokens, after `modify` changed its data.
+    fn holder_with(checks: HolderChecks<'_>, modify: impl FnOnce(&mut [u8])) -> ProgramResult {
+        let (mint, owner) = ([1u8; 32], [2u8; 32]);
+        let mut data = token_account_data(&mint, &owner, 10);
+        modify(&mut data);
+
+        let mut token_account = TestAccount::new([3u8; 32], TOKENKEG, &data);
+        let mut owner_account = TestAccount::new(owner, [0u8; 32], &[]);
+        let mut mint_account = TestAccount::new(mint, TOKENKEG, &mint_data(None, 10, 6, None));
+
+        assert_holder_with(
+            &token_account.info(),
+            &owner_account.info(),
+            &mint_account.info(),
+            checks,
+            ProgramError::Custom(7),
+        )
+    }
+
+    fn set_delegate(data: &mut [u8], delegate: &Pubkey, delegated_amount: u64) {
+        data[72..76].copy_from_slice(&1u32.to_le_bytes());
+        data[76..108].copy_from_slice(delegate);
+        data[121..129].copy_from_slice(&delegated_amount.to_le_bytes());
+    }
+
+    #[test]
+    fn holder_with_default_checks() {
+        let error = Err(ProgramError::Custom(7));
+
+        assert!(holder_with(HolderChecks::default(), |_| {}).is_ok());
+        // another owner
+        assert_eq!(
+            holder_with(HolderChecks::default(), |data| data[32] = 9),
+            error
+        );
+        // another mint
+        assert_eq!(
+            holder_with(HolderChecks::default(), |data| data[0] = 9),
+            error
+        );
+        // empty
+        assert_eq!(
+            holder_with(HolderChecks::default(), |data| data[64..72].fill(0)),
+            error
+        );
+        // not owned by a token program
+        let mut token_account = TestAccount::new(
+            [3u8; 32],
+            [9u8; 32],
+            &token_account_data(&[1u8; 32], &[2u8; 32], 10),
+        );
+        let mut owner_account = TestAccount::new([2u8; 32], [0u8; 32], &[]);
+        let mut mint_account = TestAccount::new([1u8; 32], TOKENKEG, &mint_data(None, 10, 6, None));
+        assert_eq!(
+            assert_holder_with(
+                &token_account.info(),
+                &owner_account.info(),
+                &mint_account.info(),
+                HolderChecks::default(),
+                ProgramError::Custom(7),
+            ),
+            error
+        );
+    }
+
+    #[test]
+    fn holder_with_min_amount() {
+        let checks = HolderChecks {
+            min_amount: 10,
+            ..HolderChecks::default()
+        };
+        assert!(holder_with(checks, |_| {}).is_ok());
+
+        let checks = HolderChecks {
+            min_amount: 11,
+            ..HolderChecks::default()
+        };
+        assert_eq!(holder_with(checks, |_| {}), Err(ProgramError::Custom(7)));
+    }
+
+    #[test]
+    fn holder_with_frozen() {
+        // AccountState::Frozen
+        let freeze = |data: &mut [u8]| data[108] = 2;
+
+        assert!(holder_with(HolderChecks::default(), freeze).is_ok());
+
+        let checks = HolderChecks {
+            allow_frozen: false,
+            ..HolderChecks::default()
+        };
+        assert!(holder_with(checks, |_| {}).is_ok());
+        assert_eq!(holder_with(checks, freeze), Err(ProgramError::Custom(7)));
+    }
+
+    #[test]
+    fn holder_with_delegate() {
+        let delegate = [5u8; 32];
+        let delegated = |data: &mut [u8]| set_delegate(data, &delegate, 5);
+
+        let forbidden = HolderChecks {
+            delegate: DelegateCheck::Forbidden,
+            ..HolderChecks::default()
+        };
+        assert!(holder_with(forbidden, |_| {}).is_ok());
+        assert_eq!(
+            holder_with(forbidden, delegated),
+            Err(ProgramError::Custom(7))
+        );
+
+        let required = |delegate, min_delegated_amount| HolderChecks {
+            delegate: DelegateCheck::Required {
+                delegate,
+                min_delegated_amount,
+            },
+            ..HolderChecks::default()
+        };
+        assert!(holder_with(required(&delegate, 5), delegated).is_ok());
+        assert_eq!(
+            holder_with(required(&delegate, 5), |_| {}),
+            Err(ProgramError::Custom(7))
+        );
+        assert_eq!(
+            holder_with(required(&delegate, 6), delegated),
+            Err(ProgramError::Custom(7))
+        );
+        assert_eq!(
+            holder_with(required(&[6u8; 32], 5), delegated),
+            Err(ProgramError::Custom(7))
+        );
+    }
+
+    #[test]
+    fn holder_with_close_authority() {
+        let with_close_authority = |data: &mut [u8]| {
+            data[129..133].copy_from_slice(&1u32.to_le_bytes());
+            data[133..165].copy_from_slice(&[6u8; 32]);
+        };
+
+        assert!(holder_with(HolderChecks::default(), with_close_authority).is_ok());
+
+        let checks = HolderChecks {
+            allow_close_authority: false,
+            ..HolderChecks::default()
+        };
+        assert!(holder_with(checks, |_| {}).is_ok());
+        assert_eq!(
+            holder_with(checks, with_close_authority),
+            Err(ProgramError::Custom(7))
+        );
+    }
+
+    #[test]
+    fn is_ata_rejects_unknown_token_program() {
+        let mut wallet = TestAccount::new([1u8; 32], [0u8; 32], &[]);
+        let mut mint = TestAccount::new([2u8; 32], TOKENKEG, &mint_data(None, 0, 6, None));
+        let mut token_program = TestAccount::new([9u8; 32], [0u8; 32], &[]);
+        let mut ata = TestAccount::new([3u8; 32], TOKENKEG, &[]);
+
+        assert_eq!(
+            assert_is_ata(
+                &wallet.info(),
+                &mint.info(),
+                &token_program.info(),
+                &ata.info(),
+                ProgramError::Custom(7),
+            ),
+            Err(ProgramError::Custom(7))
+        );
+    }
+
+    fn multisig_data(m: u8, members: &[Pubkey]) -> Vec<u8> {
+        let mut data = vec![0u8; Multisig::LEN];
+        data[0] = m;
+        data[1] = members.len() as u8;
+        data[2] = 1;
+        for (i, member) in members.iter().enumerate() {
+            data[3 + i * 32..3 + (i + 1) * 32].copy_from_slice(member);
+        }
+        data
+    }
+
+    #[test]
+    fn multisig_signers() {
+        let members = [[1u8; 32], [2u8; 32], [3u8; 32]];
+        let mut multisig = TestAccount::new([9u8; 32], TOKENKEG, &multisig_data(2, &members));
+        let mut first = TestAccount::with_signer(members[0], [0u8; 32], &[], true);
+        let mut second = TestAccount::with_signer(members[1], [0u8; 32], &[], true);
+        let mut third = TestAccount::new(members[2], [0u8; 32], &[]);
+        let (multisig, first, second, third) =
+            (multisig.info(), first.info(), second.info(), third.info());
+        let error = ProgramError::Custom(7);
+
+        assert!(assert_multisig_signers(&multisig, &[&first, &second], 2, error).is_ok());
+        // a member only counts once
+        assert_eq!(
+            assert_multisig_signers(&multisig, &[&first, &first], 2, error),
+            Err(error)
+        );
+        // a member that didn't sign
+        assert_eq!(
+            assert_multisig_signers(&multisig, &[&first, &third], 2, error),
+            Err(error)
+        );
+    }
+
+    #[test]
+    fn multisig_signers_rejects_longer_account() {
+        let member = [1u8; 32];
+        let mut data = multisig_data(1, &[member]);
+        data.push(0);
+        let mut multisig = TestAccount::new([9u8; 32], TOKENKEG, &data);
+        let mut signer = TestAccount::with_signer(member, [0u8; 32], &[], true);
+
+        assert_eq!(
+            assert_multisig_signers(
+                &multisig.info(),
+                &[&signer.info()],
+                1,
+                ProgramError::Custom(7)
+            ),
+            Err(ProgramError::Custom(7))
+        );
+    }
+
+    /// An extended Token-2022 mint carrying `(type, length)` extensions with zeroed values
+    fn extended_mint(extensions: &[(u16, u16)]) -> TestAccount {
+        let mut data = mint_data(None, 0, 6, None);
+        data.resize(TokenAccount::LEN, 0);
+        // AccountType::Mint
+        data.push(1);
+        for (extension_type, length) in extensions {
+            data.extend_from_slice(&extension_type.to_le_bytes());
+            data.extend_from_slice(&length.to_le_bytes());
+            data.resize(data.len() + *length as usize, 0);
+        }
+
+        TestAccount::new([1u8; 32], SPL_TOKEN_PROGRAM_IDS[1], &data)
+    }
+
+    #[test]
+    fn mint_extensions_rejects_restricted() {
+        let error = ProgramError::Custom(7);
+        let mut account = extended_mint(&[
+            (ExtensionType::MintCloseAuthority as u16, 32),
+            (ExtensionType::PermanentDelegate as u16, 32),
+        ]);
+        let info = account.info();
+
+        assert_eq!(assert_mint_extensions(&info, &[], error), Err(error));
+        assert!(assert_mint_extensions(&info, &[ExtensionType::PermanentDelegate], error).is_ok());
+    }
+
+    #[test]
+    fn mint_extensions_accepts_unrestricted() {
+        let error = ProgramError::Custom(7);
+        let mut base_mint = TestAccount::new([1u8; 32], TOKENKEG, &mint_data(None, 0, 6, None));
+        // MintCloseAuthority and an extension type this crate doesn't know about
+        let mut extended =
+            extended_mint(&[(ExtensionType::MintCloseAuthority as u16, 32), (500, 8)]);
+
+        assert!(assert_mint_extensions(&base_mint.info(), &[], error).is_ok());
+        assert!(assert_mint_extensions(&extended.info(), &[], error).is_ok());
+    }
+
+    #[test]
+    fn token_account_loaders_reject_multisig() {
+        // a multisig whose bytes read as an initialized token account
+        let mut data = multisig_data(1, &[[1u8; 32], [2u8; 32], [3u8; 32], [4u8; 32]]);
+        data[108] = 1;
+        let mut multisig = TestAccount::new([9u8; 32], TOKENKEG, &data);
+        let info = multisig.info();
+
+        assert!(info.try_to_token_account(ProgramError::Custom(7)).is_err());
+        assert!(info
+            .try_to_token_account_unchecked(ProgramError::Custom(7))
+            .is_err());
+    }
+
+    #[test]
+    fn token_account_loaders_accept_extended_token_account() {
+        let mut data = token_account_data(&[1u8; 32], &[2u8; 32], 10);
+        // AccountType::Account followed by an ImmutableOwner entry
+        data.push(2);
+        data.extend_from_slice(&7u16.to_le_bytes());
+        data.extend_from_slice(&0u16.to_le_bytes());
+        let mut token_account = TestAccount::new([3u8; 32], SPL_TOKEN_PROGRAM_IDS[1], &data);
+
+        let token_account = token_account.info();
+        let token_account = token_account
+            .try_to_token_account(ProgramError::Custom(7))
+            .unwrap();
+        assert_eq!(token_account.amount(), 10);
+    }
+}
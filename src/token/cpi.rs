@@ -1,20 +1,26 @@
-use core::array;
+use pinocchio::{account_info::Ref, program_error::ProgramError, ProgramResult};
 
-use pinocchio::{
-    account_info::AccountInfo,
-    instruction::{Seed, Signer},
-    pubkey::MAX_SEEDS,
-    ProgramResult,
+use pinocchio_associated_token_account::instructions::CreateIdempotent;
+use pinocchio_system::instructions::Transfer as SystemTransfer;
+use pinocchio_token::state::TokenAccount;
+use pinocchio_token_2022::instructions::{
+    InitializeAccount3, InitializeMint2, SyncNative, Transfer, TransferChecked,
 };
 
-use pinocchio_token::instructions::CloseAccount;
-#[cfg(not(feature = "token-2022"))]
-use pinocchio_token::instructions::{Burn, MintTo};
-
-#[cfg(feature = "token-2022")]
-use pinocchio_token_2022::instructions::{Burn, MintTo};
-
-use crate::token::cpi_params::*;
+use crate::{
+    create_or_allocate_account_with_owner,
+    token::{
+        assert_token_program_matches_package,
+        cpi_params::*,
+        get_mint_len, get_transfer_hook_program_id,
+        instructions::{
+            Approve, ApproveChecked, Burn, CloseAccount, FreezeAccount, GetAccountDataSize, MintTo,
+            Revoke, SetAuthority, ThawAccount, TransferCheckedWithHook,
+        },
+        ToTokenAccount, TransferHookAccounts,
+    },
+    PdaSigners,
+};
 
 pub fn spl_token_burn(params: TokenBurnParams<'_, '_>) -> ProgramResult {
     let TokenBurnParams {
@@ -23,70 +29,24 @@ pub fn spl_token_burn(params: TokenBurnParams<'_, '_>) -> ProgramResult {
         authority,
         token_program,
         amount,
+        multisig_signers,
         authority_signer_seeds,
     } = params;
 
-    let mut seed_buffer: [Seed; MAX_SEEDS] = array::from_fn(|_| Seed::from(&[]));
+    assert_token_program_matches_package(token_program, ProgramError::IncorrectProgramId)?;
 
-    #[cfg(feature = "token-2022")]
-    let sized_token_program = {
-        let try_sized_token_program = token_program.try_borrow_data().expect("borrow failed");
-        let sized: &[u8; 32] = try_sized_token_program
-            .as_ref()
-            .try_into()
-            .expect("wrong account length");
-        sized
+    let instruction = Burn {
+        account: source,
+        mint,
+        authority,
+        multisig_signers,
+        amount,
+        token_program: token_program.key(),
     };
-    // COPY SEEDS IN A LOOP
-    // This is very cheap. It just copies pointers (references), not data.
-    if let Some(authority_signer) = authority_signer_seeds {
-        for (i, raw_seed) in authority_signer.iter().enumerate() {
-            seed_buffer[i] = Seed::from(*raw_seed);
-        }
-        let total_seeds = authority_signer.len();
-
-        let active_seeds = &seed_buffer[0..total_seeds];
-
-        let signer = Signer::from(active_seeds);
 
-        #[cfg(feature = "token-2022")]
-        Burn {
-            token_program: sized_token_program,
-            account: source,
-            amount,
-            authority,
-            mint,
-        }
-        .invoke_signed(&[signer])?;
-
-        #[cfg(not(feature = "token-2022"))]
-        Burn {
-            account: source,
-            amount,
-            authority,
-            mint,
-        }
-        .invoke_signed(&[signer])?;
-    } else {
-        #[cfg(feature = "token-2022")]
-        Burn {
-            token_program: sized_token_program,
-            account: source,
-            amount,
-            authority,
-            mint,
-        }
-        .invoke()?;
+    let signers = PdaSigners::new(authority_signer_seeds)?;
+    instruction.invoke_signed(&signers)?;
 
-        #[cfg(not(feature = "token-2022"))]
-        Burn {
-            account: source,
-            amount,
-            authority,
-            mint,
-        }
-        .invoke()?;
-    }
     Ok(())
 }
 
@@ -97,136 +57,508 @@ pub fn spl_token_mint_to(params: TokenMintToParams<'_, '_>) -> ProgramResult {
         authority,
         token_program,
         amount,
+        multisig_signers,
         authority_signer_seeds,
     } = params;
 
-    let mut seed_buffer: [Seed; MAX_SEEDS] = array::from_fn(|_| Seed::from(&[]));
-    #[cfg(feature = "token-2022")]
-    let sized_token_program = {
-        let try_sized_token_program = token_program.try_borrow_data().expect("borrow failed");
-        let sized: &[u8; 32] = try_sized_token_program
-            .as_ref()
-            .try_into()
-            .expect("wrong account length");
-        sized
+    assert_token_program_matches_package(token_program, ProgramError::IncorrectProgramId)?;
+
+    let instruction = MintTo {
+        mint,
+        account: destination,
+        mint_authority: authority,
+        multisig_signers,
+        amount,
+        token_program: token_program.key(),
     };
 
-    if let Some(authority_signer) = authority_signer_seeds {
-        for (i, raw_seed) in authority_signer.iter().enumerate() {
-            seed_buffer[i] = Seed::from(*raw_seed);
-        }
-        let total_seeds = authority_signer.len();
+    let signers = PdaSigners::new(authority_signer_seeds)?;
+    instruction.invoke_signed(&signers)?;
 
-        let active_seeds = &seed_buffer[0..total_seeds];
+    Ok(())
+}
 
-        let signer = Signer::from(active_seeds);
+pub fn spl_token_close(params: TokenCloseParams<'_, '_>) -> ProgramResult {
+    let TokenCloseParams {
+        account,
+        destination,
+        owner,
+        multisig_signers,
+        authority_signer_seeds,
+        token_program,
+    } = params;
 
-        #[cfg(not(feature = "token-2022"))]
-        MintTo {
-            mint,
-            account: destination,
-            amount,
-            mint_authority: authority,
-        }
-        .invoke_signed(&[signer])?;
+    assert_token_program_matches_package(token_program, ProgramError::IncorrectProgramId)?;
 
-        #[cfg(feature = "token-2022")]
-        MintTo {
-            account: destination,
-            amount,
-            mint,
-            mint_authority: authority,
-            token_program: sized_token_program,
-        }
-        .invoke_signed(&[signer])?;
-    } else {
-        #[cfg(not(feature = "token-2022"))]
-        MintTo {
-            mint,
-            account: destination,
-            amount,
-            mint_authority: authority,
-        }
-        .invoke()?;
+    let instruction = CloseAccount {
+        account,
+        destination,
+        authority: owner,
+        multisig_signers,
+        token_program: token_program.key(),
+    };
 
-        #[cfg(feature = "token-2022")]
-        MintTo {
-            account: destination,
-            amount,
-            mint,
-            mint_authority: authority,
-            token_program: sized_token_program,
-        }
-        .invoke()?;
-    }
+    let signers = PdaSigners::new(authority_signer_seeds)?;
+    instruction.invoke_signed(&signers)?;
 
     Ok(())
 }
 
-pub fn spl_token_close(params: TokenCloseParams<'_, '_>) -> ProgramResult {
-    let TokenCloseParams {
+pub fn spl_token_approve(params: TokenApproveParams<'_, '_>) -> ProgramResult {
+    let TokenApproveParams {
+        source,
+        delegate,
+        amount,
+        authority,
+        multisig_signers,
+        authority_signer_seeds,
+        token_program,
+    } = params;
+
+    assert_token_program_matches_package(token_program, ProgramError::IncorrectProgramId)?;
+
+    let instruction = Approve {
+        source,
+        delegate,
+        authority,
+        multisig_signers,
+        amount,
+        token_program: token_program.key(),
+    };
+
+    let signers = PdaSigners::new(authority_signer_seeds)?;
+    instruction.invoke_signed(&signers)?;
+
+    Ok(())
+}
+
+pub fn spl_token_approve_checked(params: TokenApproveCheckedParams<'_, '_>) -> ProgramResult {
+    let TokenApproveCheckedParams {
+        source,
+        mint,
+        delegate,
+        amount,
+        decimals,
+        authority,
+        multisig_signers,
+        authority_signer_seeds,
+        token_program,
+    } = params;
+
+    assert_token_program_matches_package(token_program, ProgramError::IncorrectProgramId)?;
+
+    let instruction = ApproveChecked {
+        source,
+        mint,
+        delegate,
+        authority,
+        multisig_signers,
+        amount,
+        decimals,
+        token_program: token_program.key(),
+    };
+
+    let signers = PdaSigners::new(authority_signer_seeds)?;
+    instruction.invoke_signed(&signers)?;
+
+    Ok(())
+}
+
+pub fn spl_token_revoke(params: TokenRevokeParams<'_, '_>) -> ProgramResult {
+    let TokenRevokeParams {
+        source,
+        authority,
+        multisig_signers,
+        authority_signer_seeds,
+        token_program,
+    } = params;
+
+    assert_token_program_matches_package(token_program, ProgramError::IncorrectProgramId)?;
+
+    let instruction = Revoke {
+        source,
+        authority,
+        multisig_signers,
+        token_program: token_program.key(),
+    };
+
+    let signers = PdaSigners::new(authority_signer_seeds)?;
+    instruction.invoke_signed(&signers)?;
+
+    Ok(())
+}
+
+pub fn spl_token_set_authority(params: TokenSetAuthorityParams<'_, '_>) -> ProgramResult {
+    let TokenSetAuthorityParams {
+        account,
+        authority,
+        authority_type,
+        new_authority,
+        multisig_signers,
+        authority_signer_seeds,
+        token_program,
+    } = params;
+
+    assert_token_program_matches_package(token_program, ProgramError::IncorrectProgramId)?;
+
+    let instruction = SetAuthority {
         account,
+        authority,
+        multisig_signers,
+        authority_type,
+        new_authority,
+        token_program: token_program.key(),
+    };
+
+    let signers = PdaSigners::new(authority_signer_seeds)?;
+    instruction.invoke_signed(&signers)?;
+
+    Ok(())
+}
+
+pub fn spl_token_freeze(params: TokenFreezeParams<'_, '_>) -> ProgramResult {
+    let TokenFreezeParams {
+        account,
+        mint,
+        freeze_authority,
+        multisig_signers,
+        authority_signer_seeds,
+        token_program,
+    } = params;
+
+    assert_token_program_matches_package(token_program, ProgramError::IncorrectProgramId)?;
+
+    let instruction = FreezeAccount {
+        account,
+        mint,
+        freeze_authority,
+        multisig_signers,
+        token_program: token_program.key(),
+    };
+
+    let signers = PdaSigners::new(authority_signer_seeds)?;
+    instruction.invoke_signed(&signers)?;
+
+    Ok(())
+}
+
+pub fn spl_token_thaw(params: TokenThawParams<'_, '_>) -> ProgramResult {
+    let TokenThawParams {
+        account,
+        mint,
+        freeze_authority,
+        multisig_signers,
+        authority_signer_seeds,
+        token_program,
+    } = params;
+
+    assert_token_program_matches_package(token_program, ProgramError::IncorrectProgramId)?;
+
+    let instruction = ThawAccount {
+        account,
+        mint,
+        freeze_authority,
+        multisig_signers,
+        token_program: token_program.key(),
+    };
+
+    let signers = PdaSigners::new(authority_signer_seeds)?;
+    instruction.invoke_signed(&signers)?;
+
+    Ok(())
+}
+
+pub fn spl_token_transfer(params: TokenTransferParams<'_, '_>) -> ProgramResult {
+    let TokenTransferParams {
+        source,
         destination,
-        owner,
+        amount,
+        authority,
         authority_signer_seeds,
         token_program,
     } = params;
 
-    let mut seed_buffer: [Seed; MAX_SEEDS] = array::from_fn(|_| Seed::from(&[]));
-    #[cfg(feature = "token-2022")]
-    let sized_token_program = {
-        let try_sized_token_program = token_program.try_borrow_data().expect("borrow failed");
-        let sized: &[u8; 32] = try_sized_token_program
-            .as_ref()
-            .try_into()
-            .expect("wrong account length");
-        sized
+    assert_token_program_matches_package(token_program, ProgramError::IncorrectProgramId)?;
+
+    let instruction = Transfer {
+        from: source,
+        to: destination,
+        authority,
+        amount,
+        token_program: token_program.key(),
     };
 
-    if let Some(authority_signer) = authority_signer_seeds {
-        for (i, raw_seed) in authority_signer.iter().enumerate() {
-            seed_buffer[i] = Seed::from(*raw_seed);
-        }
-        let total_seeds = authority_signer.len();
+    let signers = PdaSigners::new(authority_signer_seeds)?;
+    instruction.invoke_signed(&signers)?;
+
+    Ok(())
+}
+
+pub fn spl_token_transfer_checked(params: TokenTransferCheckedParams<'_, '_>) -> ProgramResult {
+    let TokenTransferCheckedParams {
+        source,
+        mint,
+        destination,
+        amount,
+        decimals,
+        authority,
+        authority_signer_seeds,
+        token_program,
+    } = params;
 
-        let active_seeds = &seed_buffer[0..total_seeds];
+    assert_token_program_matches_package(token_program, ProgramError::IncorrectProgramId)?;
 
-        let signer = Signer::from(active_seeds);
+    let instruction = TransferChecked {
+        from: source,
+        mint,
+        to: destination,
+        authority,
+        amount,
+        decimals,
+        token_program: token_program.key(),
+    };
 
-        #[cfg(feature = "token-2022")]
-        CloseAccount {
-            account,
-            authority: owner,
-            destination,
-            token_program: sized_token_program,
-        }
-        .invoke_signed(&[signer])?;
+    let signers = PdaSigners::new(authority_signer_seeds)?;
+    instruction.invoke_signed(&signers)?;
+
+    Ok(())
+}
+
+/// Same as [`spl_token_transfer_checked`], but when the mint has a transfer hook
+/// the accounts the hook requires are resolved from `remaining_accounts` and
+/// appended to the CPI.
+pub fn spl_token_transfer_checked_with_hook(
+    params: TokenTransferCheckedWithHookParams<'_, '_>,
+) -> ProgramResult {
+    let TokenTransferCheckedWithHookParams {
+        source,
+        mint,
+        destination,
+        amount,
+        decimals,
+        authority,
+        remaining_accounts,
+        authority_signer_seeds,
+        token_program,
+    } = params;
+
+    assert_token_program_matches_package(token_program, ProgramError::IncorrectProgramId)?;
 
-        #[cfg(not(feature = "token-2022"))]
-        CloseAccount {
-            account,
-            authority: owner,
-            destination,
+    let signers = PdaSigners::new(authority_signer_seeds)?;
+
+    let Some(hook_program_id) = get_transfer_hook_program_id(mint)? else {
+        TransferChecked {
+            from: source,
+            mint,
+            to: destination,
+            authority,
+            amount,
+            decimals,
+            token_program: token_program.key(),
         }
-        .invoke_signed(&[signer])?;
+        .invoke_signed(&signers)?;
+
+        return Ok(());
+    };
+
+    let transfer_hook = TransferHookAccounts::resolve(
+        &hook_program_id,
+        source,
+        mint,
+        destination,
+        authority,
+        amount,
+        remaining_accounts,
+    )?;
+
+    let instruction = TransferCheckedWithHook {
+        from: source,
+        mint,
+        to: destination,
+        authority,
+        amount,
+        decimals,
+        transfer_hook: &transfer_hook,
+        token_program: token_program.key(),
+    };
+
+    instruction.invoke_signed(&signers)?;
+
+    Ok(())
+}
+
+/// Wraps `lamports` of SOL: moves them from `payer` into the native token
+/// account, then syncs its token amount with its lamports.
+pub fn wrap_sol(params: WrapSolParams<'_, '_>) -> ProgramResult {
+    let WrapSolParams {
+        payer,
+        native_token_account,
+        lamports,
+        payer_signer_seeds,
+        token_program,
+    } = params;
+
+    assert_token_program_matches_package(token_program, ProgramError::IncorrectProgramId)?;
+
+    let signers = PdaSigners::new(payer_signer_seeds)?;
+    SystemTransfer {
+        from: payer,
+        to: native_token_account,
+        lamports,
+    }
+    .invoke_signed(&signers)?;
+
+    SyncNative {
+        native_token: native_token_account,
+        token_program: token_program.key(),
+    }
+    .invoke()
+}
+
+/// Unwraps SOL by closing a native token account: all of its lamports, wrapped
+/// and rent, go to `destination`.
+pub fn unwrap_sol(params: TokenCloseParams<'_, '_>) -> ProgramResult {
+    let is_native = params
+        .account
+        .try_to_token_account(ProgramError::UninitializedAccount)?
+        .is_native();
+
+    if !is_native {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    spl_token_close(params)
+}
+
+/// Creates the associated token account of `wallet` for `mint` if it doesn't
+/// exist yet, then returns it parsed as a `TokenAccount`.
+pub fn create_ata_if_needed<'a>(
+    params: CreateAtaParams<'a, '_>,
+) -> Result<Ref<'a, TokenAccount>, ProgramError> {
+    let CreateAtaParams {
+        payer,
+        ata,
+        wallet,
+        mint,
+        payer_signer_seeds,
+        system_program,
+        token_program,
+    } = params;
+
+    assert_token_program_matches_package(token_program, ProgramError::IncorrectProgramId)?;
+
+    let instruction = CreateIdempotent {
+        funding_account: payer,
+        account: ata,
+        wallet,
+        mint,
+        system_program,
+        token_program,
+    };
+
+    let signers = PdaSigners::new(payer_signer_seeds)?;
+    instruction.invoke_signed(&signers)?;
+
+    ata.try_to_token_account(ProgramError::UninitializedAccount)
+}
+
+/// Creates a PDA token account owned by the token program and initializes it
+/// for `mint` and `owner`. On Token-2022 the account is sized for the
+/// extensions the mint requires on its token accounts.
+pub fn create_token_account_pda(params: CreateTokenAccountPdaParams<'_, '_>) -> ProgramResult {
+    let CreateTokenAccountPdaParams {
+        program_id,
+        token_account,
+        mint,
+        owner,
+        payer,
+        signer_seeds,
+        system_program,
+        token_program,
+    } = params;
+
+    assert_token_program_matches_package(token_program, ProgramError::IncorrectProgramId)?;
+
+    let size = if token_program.key() == &pinocchio_token::ID {
+        TokenAccount::LEN
     } else {
-        #[cfg(feature = "token-2022")]
-        CloseAccount {
-            account,
-            authority: owner,
-            destination,
-            token_program: sized_token_program,
+        GetAccountDataSize {
+            mint,
+            token_program: token_program.key(),
         }
-        .invoke()?;
+        .invoke()?
+    };
 
-        #[cfg(not(feature = "token-2022"))]
-        CloseAccount {
-            account,
-            authority: owner,
-            destination,
-        }
-        .invoke()?;
+    create_or_allocate_account_with_owner(
+        program_id,
+        token_program.key(),
+        token_account,
+        system_program,
+        payer,
+        size,
+        signer_seeds,
+    )?;
+
+    InitializeAccount3 {
+        account: token_account,
+        mint,
+        owner,
+        token_program: token_program.key(),
     }
+    .invoke()
+}
 
-    Ok(())
+/// Creates a PDA mint owned by the token program and initializes it with
+/// InitializeMint2. On Token-2022 the account is sized for `extensions`, which
+/// `initialize_extensions` has to initialize, so passing extensions without it
+/// fails with `InvalidArgument`.
+pub fn create_mint_pda(params: CreateMintPdaParams<'_, '_>) -> ProgramResult {
+    let CreateMintPdaParams {
+        program_id,
+        mint,
+        decimals,
+        mint_authority,
+        freeze_authority,
+        extensions,
+        initialize_extensions,
+        payer,
+        signer_seeds,
+        system_program,
+        token_program,
+    } = params;
+
+    assert_token_program_matches_package(token_program, ProgramError::IncorrectProgramId)?;
+
+    // Tokenkeg mints can't carry extensions
+    if token_program.key() == &pinocchio_token::ID && !extensions.is_empty() {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // the account would otherwise be sized for extensions nothing initializes
+    if !extensions.is_empty() && initialize_extensions.is_none() {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    create_or_allocate_account_with_owner(
+        program_id,
+        token_program.key(),
+        mint,
+        system_program,
+        payer,
+        get_mint_len(extensions)?,
+        signer_seeds,
+    )?;
+
+    if let Some(initialize_extensions) = initialize_extensions {
+        initialize_extensions()?;
+    }
+
+    InitializeMint2 {
+        mint,
+        decimals,
+        mint_authority,
+        freeze_authority,
+        token_program: token_program.key(),
+    }
+    .invoke()
 }
@@ -1,4 +1,6 @@
-use pinocchio::account_info::AccountInfo;
+use pinocchio::{account_info::AccountInfo, pubkey::Pubkey, ProgramResult};
+
+use crate::{token::ExtensionType, SignerSeeds};
 
 /// TokenBurnParams
 #[derive(Clone, Copy)]
@@ -11,8 +13,10 @@ pub struct TokenBurnParams<'a: 'b, 'b> {
     pub amount: u64,
     /// authority
     pub authority: &'a AccountInfo,
-    /// authority_signer_seeds
-    pub authority_signer_seeds: Option<&'b [&'b [u8]]>,
+    /// multisig_signers, when `authority` is a token multisig account
+    pub multisig_signers: &'b [&'a AccountInfo],
+    /// authority_signer_seeds, one entry per PDA signing the CPI
+    pub authority_signer_seeds: &'b [SignerSeeds<'b>],
     /// token_program
     pub token_program: &'a AccountInfo,
 }
@@ -28,8 +32,10 @@ pub struct TokenMintToParams<'a: 'b, 'b> {
     pub amount: u64,
     /// authority
     pub authority: &'a AccountInfo,
-    /// authority_signer_seeds
-    pub authority_signer_seeds: Option<&'b [&'b [u8]]>,
+    /// multisig_signers, when `authority` is a token multisig account
+    pub multisig_signers: &'b [&'a AccountInfo],
+    /// authority_signer_seeds, one entry per PDA signing the CPI
+    pub authority_signer_seeds: &'b [SignerSeeds<'b>],
     /// token_program
     pub token_program: &'a AccountInfo,
 }
@@ -43,8 +49,307 @@ pub struct TokenCloseParams<'a: 'b, 'b> {
     pub destination: &'a AccountInfo,
     /// Owner of the token account.
     pub owner: &'a AccountInfo,
-    /// authority_signer_seeds
-    pub authority_signer_seeds: Option<&'b [&'b [u8]]>,
+    /// multisig_signers, when `owner` is a token multisig account
+    pub multisig_signers: &'b [&'a AccountInfo],
+    /// authority_signer_seeds, one entry per PDA signing the CPI
+    pub authority_signer_seeds: &'b [SignerSeeds<'b>],
+    /// token_program
+    pub token_program: &'a AccountInfo,
+}
+
+/// TokenApproveParams
+#[derive(Clone, Copy)]
+pub struct TokenApproveParams<'a: 'b, 'b> {
+    /// source
+    pub source: &'a AccountInfo,
+    /// delegate allowed to move or burn up to `amount` of the source
+    pub delegate: &'a AccountInfo,
+    /// amount
+    pub amount: u64,
+    /// authority, the owner of the source
+    pub authority: &'a AccountInfo,
+    /// multisig_signers, when `authority` is a token multisig account
+    pub multisig_signers: &'b [&'a AccountInfo],
+    /// authority_signer_seeds, one entry per PDA signing the CPI
+    pub authority_signer_seeds: &'b [SignerSeeds<'b>],
+    /// token_program
+    pub token_program: &'a AccountInfo,
+}
+
+/// TokenApproveCheckedParams
+#[derive(Clone, Copy)]
+pub struct TokenApproveCheckedParams<'a: 'b, 'b> {
+    /// source
+    pub source: &'a AccountInfo,
+    /// mint
+    pub mint: &'a AccountInfo,
+    /// delegate allowed to move or burn up to `amount` of the source
+    pub delegate: &'a AccountInfo,
+    /// amount
+    pub amount: u64,
+    /// decimals of the mint, checked by the token program
+    pub decimals: u8,
+    /// authority, the owner of the source
+    pub authority: &'a AccountInfo,
+    /// multisig_signers, when `authority` is a token multisig account
+    pub multisig_signers: &'b [&'a AccountInfo],
+    /// authority_signer_seeds, one entry per PDA signing the CPI
+    pub authority_signer_seeds: &'b [SignerSeeds<'b>],
+    /// token_program
+    pub token_program: &'a AccountInfo,
+}
+
+/// TokenRevokeParams
+#[derive(Clone, Copy)]
+pub struct TokenRevokeParams<'a: 'b, 'b> {
+    /// source
+    pub source: &'a AccountInfo,
+    /// authority, the owner of the source
+    pub authority: &'a AccountInfo,
+    /// multisig_signers, when `authority` is a token multisig account
+    pub multisig_signers: &'b [&'a AccountInfo],
+    /// authority_signer_seeds, one entry per PDA signing the CPI
+    pub authority_signer_seeds: &'b [SignerSeeds<'b>],
+    /// token_program
+    pub token_program: &'a AccountInfo,
+}
+
+/// Authorities of a mint or token account, as numbered by the token programs.
+/// Everything after `CloseAccount` only exists in Token-2022.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AuthorityType {
+    /// Authority to mint new tokens
+    MintTokens = 0,
+    /// Authority to freeze any account of the mint
+    FreezeAccount = 1,
+    /// Owner of a token account
+    AccountOwner = 2,
+    /// Authority to close a token account
+    CloseAccount = 3,
+    /// Authority to set the transfer fee
+    TransferFeeConfig = 4,
+    /// Authority to withdraw withheld transfer fees
+    WithheldWithdraw = 5,
+    /// Authority to close the mint
+    CloseMint = 6,
+    /// Authority to set the interest rate
+    InterestRate = 7,
+    /// Permanent delegate of every account of the mint
+    PermanentDelegate = 8,
+    /// Authority to update the confidential transfer mint settings
+    ConfidentialTransferMint = 9,
+    /// Authority to set the transfer hook program
+    TransferHookProgramId = 10,
+    /// Authority to set the confidential transfer fee withdrawal key
+    ConfidentialTransferFeeConfig = 11,
+    /// Authority to set the metadata address
+    MetadataPointer = 12,
+    /// Authority to set the group address
+    GroupPointer = 13,
+    /// Authority to set the group member address
+    GroupMemberPointer = 14,
+    /// Authority to set the UI amount multiplier
+    ScaledUiAmount = 15,
+    /// Authority to pause or resume the mint
+    Pause = 16,
+}
+
+/// TokenSetAuthorityParams
+#[derive(Clone, Copy)]
+pub struct TokenSetAuthorityParams<'a: 'b, 'b> {
+    /// mint or token account to change the authority of
+    pub account: &'a AccountInfo,
+    /// authority, the current holder of `authority_type`
+    pub authority: &'a AccountInfo,
+    /// authority_type
+    pub authority_type: AuthorityType,
+    /// new_authority, `None` removes the authority for good
+    pub new_authority: Option<&'b Pubkey>,
+    /// multisig_signers, when `authority` is a token multisig account
+    pub multisig_signers: &'b [&'a AccountInfo],
+    /// authority_signer_seeds, one entry per PDA signing the CPI
+    pub authority_signer_seeds: &'b [SignerSeeds<'b>],
+    /// token_program
+    pub token_program: &'a AccountInfo,
+}
+
+/// TokenFreezeParams
+#[derive(Clone, Copy)]
+pub struct TokenFreezeParams<'a: 'b, 'b> {
+    /// token account to freeze
+    pub account: &'a AccountInfo,
+    /// mint
+    pub mint: &'a AccountInfo,
+    /// the mint's freeze authority
+    pub freeze_authority: &'a AccountInfo,
+    /// multisig_signers, when `freeze_authority` is a token multisig account
+    pub multisig_signers: &'b [&'a AccountInfo],
+    /// authority_signer_seeds, one entry per PDA signing the CPI
+    pub authority_signer_seeds: &'b [SignerSeeds<'b>],
+    /// token_program
+    pub token_program: &'a AccountInfo,
+}
+
+/// TokenThawParams
+#[derive(Clone, Copy)]
+pub struct TokenThawParams<'a: 'b, 'b> {
+    /// frozen token account to thaw
+    pub account: &'a AccountInfo,
+    /// mint
+    pub mint: &'a AccountInfo,
+    /// the mint's freeze authority
+    pub freeze_authority: &'a AccountInfo,
+    /// multisig_signers, when `freeze_authority` is a token multisig account
+    pub multisig_signers: &'b [&'a AccountInfo],
+    /// authority_signer_seeds, one entry per PDA signing the CPI
+    pub authority_signer_seeds: &'b [SignerSeeds<'b>],
+    /// token_program
+    pub token_program: &'a AccountInfo,
+}
+
+/// TokenTransferParams
+#[derive(Clone, Copy)]
+pub struct TokenTransferParams<'a: 'b, 'b> {
+    /// source
+    pub source: &'a AccountInfo,
+    /// destination
+    pub destination: &'a AccountInfo,
+    /// amount
+    pub amount: u64,
+    /// authority
+    pub authority: &'a AccountInfo,
+    /// authority_signer_seeds, one entry per PDA signing the CPI
+    pub authority_signer_seeds: &'b [SignerSeeds<'b>],
+    /// token_program
+    pub token_program: &'a AccountInfo,
+}
+
+/// TokenTransferCheckedParams
+#[derive(Clone, Copy)]
+pub struct TokenTransferCheckedParams<'a: 'b, 'b> {
+    /// source
+    pub source: &'a AccountInfo,
+    /// mint
+    pub mint: &'a AccountInfo,
+    /// destination
+    pub destination: &'a AccountInfo,
+    /// amount
+    pub amount: u64,
+    /// decimals of the mint, checked by the token program
+    pub decimals: u8,
+    /// authority
+    pub authority: &'a AccountInfo,
+    /// authority_signer_seeds, one entry per PDA signing the CPI
+    pub authority_signer_seeds: &'b [SignerSeeds<'b>],
+    /// token_program
+    pub token_program: &'a AccountInfo,
+}
+
+/// TokenTransferCheckedWithHookParams
+#[derive(Clone, Copy)]
+pub struct TokenTransferCheckedWithHookParams<'a: 'b, 'b> {
+    /// source
+    pub source: &'a AccountInfo,
+    /// mint
+    pub mint: &'a AccountInfo,
+    /// destination
+    pub destination: &'a AccountInfo,
+    /// amount
+    pub amount: u64,
+    /// decimals of the mint, checked by the token program
+    pub decimals: u8,
+    /// authority
+    pub authority: &'a AccountInfo,
+    /// accounts the transfer hook's extra accounts are looked up in, usually
+    /// all the accounts the instruction received
+    pub remaining_accounts: &'a [AccountInfo],
+    /// authority_signer_seeds, one entry per PDA signing the CPI
+    pub authority_signer_seeds: &'b [SignerSeeds<'b>],
+    /// token_program
+    pub token_program: &'a AccountInfo,
+}
+
+/// WrapSolParams
+#[derive(Clone, Copy)]
+pub struct WrapSolParams<'a: 'b, 'b> {
+    /// payer the SOL is moved from, a system account
+    pub payer: &'a AccountInfo,
+    /// initialized token account of the native mint
+    pub native_token_account: &'a AccountInfo,
+    /// lamports to wrap
+    pub lamports: u64,
+    /// payer_signer_seeds, when `payer` is a PDA
+    pub payer_signer_seeds: &'b [SignerSeeds<'b>],
+    /// token_program
+    pub token_program: &'a AccountInfo,
+}
+
+/// CreateAtaParams
+#[derive(Clone, Copy)]
+pub struct CreateAtaParams<'a: 'b, 'b> {
+    /// payer funding the account creation
+    pub payer: &'a AccountInfo,
+    /// associated token account to create
+    pub ata: &'a AccountInfo,
+    /// wallet owning the associated token account
+    pub wallet: &'a AccountInfo,
+    /// mint
+    pub mint: &'a AccountInfo,
+    /// payer_signer_seeds, one entry per PDA signing the CPI
+    pub payer_signer_seeds: &'b [SignerSeeds<'b>],
+    /// system_program
+    pub system_program: &'a AccountInfo,
+    /// token_program
+    pub token_program: &'a AccountInfo,
+}
+
+/// CreateTokenAccountPdaParams
+#[derive(Clone, Copy)]
+pub struct CreateTokenAccountPdaParams<'a: 'b, 'b> {
+    /// program the token account PDA is derived from
+    pub program_id: &'b Pubkey,
+    /// token account to create
+    pub token_account: &'a AccountInfo,
+    /// mint
+    pub mint: &'a AccountInfo,
+    /// owner of the new token account
+    pub owner: &'b Pubkey,
+    /// payer funding the account creation
+    pub payer: &'a AccountInfo,
+    /// signer_seeds of the token account PDA, without the bump
+    pub signer_seeds: &'b [&'b [u8]],
+    /// system_program
+    pub system_program: &'a AccountInfo,
+    /// token_program
+    pub token_program: &'a AccountInfo,
+}
+
+/// CreateMintPdaParams
+#[derive(Clone, Copy)]
+pub struct CreateMintPdaParams<'a: 'b, 'b> {
+    /// program the mint PDA is derived from
+    pub program_id: &'b Pubkey,
+    /// mint to create
+    pub mint: &'a AccountInfo,
+    /// decimals
+    pub decimals: u8,
+    /// mint_authority
+    pub mint_authority: &'b Pubkey,
+    /// freeze_authority, `None` for a mint that can't freeze accounts
+    pub freeze_authority: Option<&'b Pubkey>,
+    /// Token-2022 extensions the mint is sized for, empty for a base mint
+    pub extensions: &'b [ExtensionType],
+    /// initialize_extensions, called once the account is allocated and before
+    /// InitializeMint2, to initialize the `extensions`. Required unless
+    /// `extensions` is empty
+    pub initialize_extensions: Option<&'b dyn Fn() -> ProgramResult>,
+    /// payer funding the account creation
+    pub payer: &'a AccountInfo,
+    /// signer_seeds of the mint PDA, without the bump
+    pub signer_seeds: &'b [&'b [u8]],
+    /// system_program
+    pub system_program: &'a AccountInfo,
     /// token_program
     pub token_program: &'a AccountInfo,
 }
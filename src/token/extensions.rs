@@ -0,0 +1,701 @@
+//! Zero-copy reader for the Token-2022 extension (TLV) area of mints and token accounts.
+//!
+//! Extended Token-2022 accounts are laid out as the base state, zero padding up to
+//! the 165 byte token account length, one `AccountType` byte and then a list of
+//! `[type: u16][length: u16][value]` entries.
+
+use core::mem::size_of;
+
+use pinocchio::{program_error::ProgramError, pubkey::Pubkey};
+use pinocchio_token::state::{AccountState, Mint, TokenAccount};
+
+use crate::token::{
+    has_layout, Multisig, ACCOUNT_TYPE_ACCOUNT, ACCOUNT_TYPE_MINT, ACCOUNT_TYPE_OFFSET,
+};
+
+/// Offset of the first TLV entry
+const TLV_START: usize = ACCOUNT_TYPE_OFFSET + 1;
+
+/// Transfer fees are expressed in basis points of the transferred amount
+const ONE_IN_BASIS_POINTS: u128 = 10_000;
+
+/// Size of the type and length header of a TLV entry
+const TLV_HEADER_LEN: usize = 4;
+
+/// Token-2022 extension types, the `u16` stored in each TLV entry
+#[repr(u16)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExtensionType {
+    Uninitialized = 0,
+    TransferFeeConfig = 1,
+    TransferFeeAmount = 2,
+    MintCloseAuthority = 3,
+    ConfidentialTransferMint = 4,
+    ConfidentialTransferAccount = 5,
+    DefaultAccountState = 6,
+    ImmutableOwner = 7,
+    MemoTransfer = 8,
+    NonTransferable = 9,
+    InterestBearingConfig = 10,
+    CpiGuard = 11,
+    PermanentDelegate = 12,
+    NonTransferableAccount = 13,
+    TransferHook = 14,
+    TransferHookAccount = 15,
+    ConfidentialTransferFeeConfig = 16,
+    ConfidentialTransferFeeAmount = 17,
+    MetadataPointer = 18,
+    TokenMetadata = 19,
+    GroupPointer = 20,
+    TokenGroup = 21,
+    GroupMemberPointer = 22,
+    TokenGroupMember = 23,
+    ConfidentialMintBurn = 24,
+    ScaledUiAmount = 25,
+    Pausable = 26,
+    PausableAccount = 27,
+}
+
+impl ExtensionType {
+    /// Returns `None` for extension types this crate doesn't know about
+    pub fn from_u16(value: u16) -> Option<Self> {
+        use ExtensionType::*;
+
+        Some(match value {
+            0 => Uninitialized,
+            1 => TransferFeeConfig,
+            2 => TransferFeeAmount,
+            3 => MintCloseAuthority,
+            4 => ConfidentialTransferMint,
+            5 => ConfidentialTransferAccount,
+            6 => DefaultAccountState,
+            7 => ImmutableOwner,
+            8 => MemoTransfer,
+            9 => NonTransferable,
+            10 => InterestBearingConfig,
+            11 => CpiGuard,
+            12 => PermanentDelegate,
+            13 => NonTransferableAccount,
+            14 => TransferHook,
+            15 => TransferHookAccount,
+            16 => ConfidentialTransferFeeConfig,
+            17 => ConfidentialTransferFeeAmount,
+            18 => MetadataPointer,
+            19 => TokenMetadata,
+            20 => GroupPointer,
+            21 => TokenGroup,
+            22 => GroupMemberPointer,
+            23 => TokenGroupMember,
+            24 => ConfidentialMintBurn,
+            25 => ScaledUiAmount,
+            26 => Pausable,
+            27 => PausableAccount,
+            _ => return None,
+        })
+    }
+
+    /// Length of this extension's value in a mint, `None` for token account
+    /// extensions and for `TokenMetadata`, which has a variable length
+    pub fn mint_value_len(self) -> Option<usize> {
+        use ExtensionType::*;
+
+        Some(match self {
+            TransferFeeConfig => 108,
+            MintCloseAuthority | PermanentDelegate => 32,
+            ConfidentialTransferMint => 65,
+            DefaultAccountState => 1,
+            NonTransferable => 0,
+            InterestBearingConfig => 52,
+            TransferHook | MetadataPointer | GroupPointer | GroupMemberPointer => 64,
+            ConfidentialTransferFeeConfig => 129,
+            TokenGroup => 80,
+            TokenGroupMember => 72,
+            ConfidentialMintBurn => 196,
+            ScaledUiAmount => 56,
+            Pausable => 33,
+            _ => return None,
+        })
+    }
+}
+
+/// Size of a mint account with the given extensions, the base 82 bytes without
+/// any. Fails with `InvalidArgument` for extensions a mint can't be sized for.
+pub fn get_mint_len(extensions: &[ExtensionType]) -> Result<usize, ProgramError> {
+    if extensions.is_empty() {
+        return Ok(Mint::LEN);
+    }
+
+    let mut len = TLV_START;
+    for (i, extension) in extensions.iter().enumerate() {
+        // an extension can only be present once
+        if extensions[..i].contains(extension) {
+            continue;
+        }
+
+        let value_len = extension
+            .mint_value_len()
+            .ok_or(ProgramError::InvalidArgument)?;
+        len += TLV_HEADER_LEN + value_len;
+    }
+
+    // the token program tells multisigs apart by their length, so it pads
+    // extended accounts that would have the same length
+    if len == Multisig::LEN {
+        len += size_of::<ExtensionType>();
+    }
+
+    Ok(len)
+}
+
+/// One raw TLV entry of the extension area
+#[derive(Clone, Copy, Debug)]
+pub struct RawExtension<'a> {
+    /// `u16` extension type as stored, see [`RawExtension::extension_type`]
+    pub raw_type: u16,
+    /// the extension's value bytes
+    pub value: &'a [u8],
+}
+
+impl<'a> RawExtension<'a> {
+    pub fn extension_type(&self) -> Option<ExtensionType> {
+        ExtensionType::from_u16(self.raw_type)
+    }
+
+    /// Reinterprets the value as the typed view `T`, if this entry is a `T`
+    pub fn get<T: Extension>(&self) -> Result<Option<&'a T>, ProgramError> {
+        if self.raw_type != T::TYPE as u16 {
+            return Ok(None);
+        }
+
+        if self.value.len() != T::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // SAFETY: the length was checked above and extension views only hold
+        // byte arrays, so they have an alignment of 1.
+        Ok(Some(unsafe { &*(self.value.as_ptr() as *const T) }))
+    }
+}
+
+/// Iterator over the TLV entries of a mint or token account.
+/// Yields an error and stops if an entry runs past the end of the data.
+pub struct ExtensionIter<'a> {
+    tlv_data: &'a [u8],
+}
+
+impl<'a> ExtensionIter<'a> {
+    /// Returns the typed view of the first `T` extension, if there is one
+    pub fn find_extension<T: Extension>(self) -> Result<Option<&'a T>, ProgramError> {
+        for extension in self {
+            if let Some(view) = extension?.get::<T>()? {
+                return Ok(Some(view));
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+impl<'a> Iterator for ExtensionIter<'a> {
+    type Item = Result<RawExtension<'a>, ProgramError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.tlv_data.len() < TLV_HEADER_LEN {
+            return None;
+        }
+
+        let raw_type = u16::from_le_bytes([self.tlv_data[0], self.tlv_data[1]]);
+        // the rest of the extension area is zero padding
+        if raw_type == ExtensionType::Uninitialized as u16 {
+            self.tlv_data = &[];
+            return None;
+        }
+
+        let len = u16::from_le_bytes([self.tlv_data[2], self.tlv_data[3]]) as usize;
+        let end = TLV_HEADER_LEN + len;
+        if end > self.tlv_data.len() {
+            self.tlv_data = &[];
+            return Some(Err(ProgramError::InvalidAccountData));
+        }
+
+        let value = &self.tlv_data[TLV_HEADER_LEN..end];
+        self.tlv_data = &self.tlv_data[end..];
+
+        Some(Ok(RawExtension { raw_type, value }))
+    }
+}
+
+/// Iterates the extensions of a mint's account data. Non-extended mints
+/// (Tokenkeg, or Token-2022 without extensions) yield nothing.
+pub fn mint_extensions(data: &[u8]) -> Result<ExtensionIter<'_>, ProgramError> {
+    if data.len() < Mint::LEN {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let extensions = extensions(data, Mint::LEN, ACCOUNT_TYPE_MINT)?;
+
+    // extended mints are zero padded up to the token account length
+    if data.len() > Mint::LEN
+        && data[Mint::LEN..ACCOUNT_TYPE_OFFSET]
+            .iter()
+            .any(|byte| *byte != 0)
+    {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    Ok(extensions)
+}
+
+/// Iterates the extensions of a token account's data. Non-extended token
+/// accounts (Tokenkeg, or Token-2022 without extensions) yield nothing.
+pub fn account_extensions(data: &[u8]) -> Result<ExtensionIter<'_>, ProgramError> {
+    if data.len() < TokenAccount::LEN {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    extensions(data, TokenAccount::LEN, ACCOUNT_TYPE_ACCOUNT)
+}
+
+fn extensions(
+    data: &[u8],
+    base_len: usize,
+    account_type: u8,
+) -> Result<ExtensionIter<'_>, ProgramError> {
+    if !has_layout(data, base_len, account_type) {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if data.len() == base_len {
+        return Ok(ExtensionIter { tlv_data: &[] });
+    }
+
+    Ok(ExtensionIter {
+        tlv_data: &data[TLV_START..],
+    })
+}
+
+/// Zero-copy typed view of an extension's value
+pub trait Extension: Sized {
+    const TYPE: ExtensionType;
+    const LEN: usize = size_of::<Self>();
+}
+
+/// `OptionalNonZeroPubkey`: all zeroes means `None`
+fn optional_pubkey(key: &Pubkey) -> Option<&Pubkey> {
+    if key == &[0u8; 32] {
+        None
+    } else {
+        Some(key)
+    }
+}
+
+/// Fee charged on transfers, effective from `epoch` on
+#[repr(C)]
+pub struct TransferFee {
+    epoch: [u8; 8],
+    maximum_fee: [u8; 8],
+    transfer_fee_basis_points: [u8; 2],
+}
+
+impl TransferFee {
+    pub fn epoch(&self) -> u64 {
+        u64::from_le_bytes(self.epoch)
+    }
+
+    pub fn maximum_fee(&self) -> u64 {
+        u64::from_le_bytes(self.maximum_fee)
+    }
+
+    pub fn transfer_fee_basis_points(&self) -> u16 {
+        u16::from_le_bytes(self.transfer_fee_basis_points)
+    }
+
+    /// Fee withheld when sending `pre_fee_amount`, rounded up and capped at the
+    /// maximum fee like the token program does. `None` on overflow.
+    pub fn calculate_fee(&self, pre_fee_amount: u64) -> Option<u64> {
+        let basis_points = self.transfer_fee_basis_points() as u128;
+        if basis_points == 0 || pre_fee_amount == 0 {
+            return Some(0);
+        }
+
+        let numerator = (pre_fee_amount as u128).checked_mul(basis_points)?;
+        let raw_fee = u64::try_from(ceil_div(numerator, ONE_IN_BASIS_POINTS)?).ok()?;
+
+        Some(raw_fee.min(self.maximum_fee()))
+    }
+
+    /// Amount that has to be sent for `post_fee_amount` to arrive. `None` on overflow.
+    pub fn calculate_pre_fee_amount(&self, post_fee_amount: u64) -> Option<u64> {
+        let maximum_fee = self.maximum_fee();
+        let basis_points = self.transfer_fee_basis_points() as u128;
+
+        match (basis_points, post_fee_amount) {
+            (0, _) => Some(post_fee_amount),
+            (_, 0) => Some(0),
+            (ONE_IN_BASIS_POINTS, _) => maximum_fee.checked_add(post_fee_amount),
+            _ => {
+                let numerator = (post_fee_amount as u128).checked_mul(ONE_IN_BASIS_POINTS)?;
+                let denominator = ONE_IN_BASIS_POINTS.checked_sub(basis_points)?;
+                let raw_pre_fee_amount = ceil_div(numerator, denominator)?;
+
+                if raw_pre_fee_amount.checked_sub(post_fee_amount as u128)? >= maximum_fee as u128 {
+                    post_fee_amount.checked_add(maximum_fee)
+                } else {
+                    u64::try_from(raw_pre_fee_amount).ok()
+                }
+            }
+        }
+    }
+
+    /// Fee withheld on a transfer that delivers `post_fee_amount`. `None` on overflow.
+    pub fn calculate_inverse_fee(&self, post_fee_amount: u64) -> Option<u64> {
+        self.calculate_fee(self.calculate_pre_fee_amount(post_fee_amount)?)
+    }
+}
+
+fn ceil_div(numerator: u128, denominator: u128) -> Option<u128> {
+    numerator
+        .checked_add(denominator)?
+        .checked_sub(1)?
+        .checked_div(denominator)
+}
+
+/// Mint extension: transfers pay a fee, withheld on the destination account
+#[repr(C)]
+pub struct TransferFeeConfig {
+    transfer_fee_config_authority: Pubkey,
+    withdraw_withheld_authority: Pubkey,
+    withheld_amount: [u8; 8],
+    older_transfer_fee: TransferFee,
+    newer_transfer_fee: TransferFee,
+}
+
+impl TransferFeeConfig {
+    pub fn transfer_fee_config_authority(&self) -> Option<&Pubkey> {
+        optional_pubkey(&self.transfer_fee_config_authority)
+    }
+
+    pub fn withdraw_withheld_authority(&self) -> Option<&Pubkey> {
+        optional_pubkey(&self.withdraw_withheld_authority)
+    }
+
+    pub fn withheld_amount(&self) -> u64 {
+        u64::from_le_bytes(self.withheld_amount)
+    }
+
+    pub fn older_transfer_fee(&self) -> &TransferFee {
+        &self.older_transfer_fee
+    }
+
+    pub fn newer_transfer_fee(&self) -> &TransferFee {
+        &self.newer_transfer_fee
+    }
+
+    /// The fee in effect during `epoch`: the newer fee once its epoch is reached
+    pub fn epoch_fee(&self, epoch: u64) -> &TransferFee {
+        if epoch >= self.newer_transfer_fee.epoch() {
+            &self.newer_transfer_fee
+        } else {
+            &self.older_transfer_fee
+        }
+    }
+}
+
+impl Extension for TransferFeeConfig {
+    const TYPE: ExtensionType = ExtensionType::TransferFeeConfig;
+}
+
+/// Mint extension: the mint can be closed by `close_authority` once supply is 0
+#[repr(C)]
+pub struct MintCloseAuthority {
+    close_authority: Pubkey,
+}
+
+impl MintCloseAuthority {
+    pub fn close_authority(&self) -> Option<&Pubkey> {
+        optional_pubkey(&self.close_authority)
+    }
+}
+
+impl Extension for MintCloseAuthority {
+    const TYPE: ExtensionType = ExtensionType::MintCloseAuthority;
+}
+
+/// Mint extension: `delegate` can transfer or burn from any account of the mint
+#[repr(C)]
+pub struct PermanentDelegate {
+    delegate: Pubkey,
+}
+
+impl PermanentDelegate {
+    pub fn delegate(&self) -> Option<&Pubkey> {
+        optional_pubkey(&self.delegate)
+    }
+}
+
+impl Extension for PermanentDelegate {
+    const TYPE: ExtensionType = ExtensionType::PermanentDelegate;
+}
+
+/// Mint extension: tokens can't be transferred
+#[repr(C)]
+pub struct NonTransferable;
+
+impl Extension for NonTransferable {
+    const TYPE: ExtensionType = ExtensionType::NonTransferable;
+}
+
+/// Mint extension: state new token accounts of the mint are created in
+#[repr(C)]
+pub struct DefaultAccountState {
+    state: u8,
+}
+
+impl DefaultAccountState {
+    /// Returns `None` if the stored state isn't a valid `AccountState`
+    pub fn state(&self) -> Option<AccountState> {
+        match self.state {
+            0 => Some(AccountState::Uninitialized),
+            1 => Some(AccountState::Initialized),
+            2 => Some(AccountState::Frozen),
+            _ => None,
+        }
+    }
+}
+
+impl Extension for DefaultAccountState {
+    const TYPE: ExtensionType = ExtensionType::DefaultAccountState;
+}
+
+/// Mint extension: points to the account holding the token metadata
+#[repr(C)]
+pub struct MetadataPointer {
+    authority: Pubkey,
+    metadata_address: Pubkey,
+}
+
+impl MetadataPointer {
+    pub fn authority(&self) -> Option<&Pubkey> {
+        optional_pubkey(&self.authority)
+    }
+
+    pub fn metadata_address(&self) -> Option<&Pubkey> {
+        optional_pubkey(&self.metadata_address)
+    }
+}
+
+impl Extension for MetadataPointer {
+    const TYPE: ExtensionType = ExtensionType::MetadataPointer;
+}
+
+/// Mint extension: every transfer CPIs into `program_id`
+#[repr(C)]
+pub struct TransferHook {
+    authority: Pubkey,
+    program_id: Pubkey,
+}
+
+impl TransferHook {
+    pub fn authority(&self) -> Option<&Pubkey> {
+        optional_pubkey(&self.authority)
+    }
+
+    pub fn program_id(&self) -> Option<&Pubkey> {
+        optional_pubkey(&self.program_id)
+    }
+}
+
+impl Extension for TransferHook {
+    const TYPE: ExtensionType = ExtensionType::TransferHook;
+}
+
+/// Token account extension: the owner can't be reassigned
+#[repr(C)]
+pub struct ImmutableOwner;
+
+impl Extension for ImmutableOwner {
+    const TYPE: ExtensionType = ExtensionType::ImmutableOwner;
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn extended_account(account_type: u8, base_len: usize, entries: &[(u16, &[u8])]) -> Vec<u8> {
+        let mut data = vec![0u8; base_len];
+        data.resize(ACCOUNT_TYPE_OFFSET, 0);
+        data.push(account_type);
+        for (raw_type, value) in entries {
+            data.extend_from_slice(&raw_type.to_le_bytes());
+            data.extend_from_slice(&(value.len() as u16).to_le_bytes());
+            data.extend_from_slice(value);
+        }
+        data
+    }
+
+    #[test]
+    fn base_mint_has_no_extensions() {
+        let data = [0u8; Mint::LEN];
+        assert_eq!(mint_extensions(&data).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn reads_mint_extensions() {
+        let delegate = [7u8; 32];
+        let data = extended_account(
+            ACCOUNT_TYPE_MINT,
+            Mint::LEN,
+            &[
+                (ExtensionType::NonTransferable as u16, &[]),
+                (ExtensionType::PermanentDelegate as u16, &delegate),
+            ],
+        );
+
+        let types: Vec<_> = mint_extensions(&data)
+            .unwrap()
+            .map(|extension| extension.unwrap().extension_type())
+            .collect();
+        assert_eq!(
+            types,
+            [
+                Some(ExtensionType::NonTransferable),
+                Some(ExtensionType::PermanentDelegate)
+            ]
+        );
+
+        let permanent_delegate = mint_extensions(&data)
+            .unwrap()
+            .find_extension::<PermanentDelegate>()
+            .unwrap()
+            .unwrap();
+        assert_eq!(permanent_delegate.delegate(), Some(&delegate));
+
+        assert!(mint_extensions(&data)
+            .unwrap()
+            .find_extension::<TransferHook>()
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn reads_account_extensions() {
+        let data = extended_account(
+            ACCOUNT_TYPE_ACCOUNT,
+            TokenAccount::LEN,
+            &[(ExtensionType::ImmutableOwner as u16, &[])],
+        );
+
+        assert!(account_extensions(&data)
+            .unwrap()
+            .find_extension::<ImmutableOwner>()
+            .unwrap()
+            .is_some());
+    }
+
+    #[test]
+    fn rejects_wrong_account_type() {
+        let data = extended_account(ACCOUNT_TYPE_ACCOUNT, TokenAccount::LEN, &[]);
+        assert!(mint_extensions(&data).is_err());
+    }
+
+    #[test]
+    fn rejects_truncated_entry() {
+        let mut data = extended_account(
+            ACCOUNT_TYPE_MINT,
+            Mint::LEN,
+            &[(ExtensionType::MintCloseAuthority as u16, &[1u8; 32])],
+        );
+        data.truncate(data.len() - 1);
+
+        let mut extensions = mint_extensions(&data).unwrap();
+        assert!(extensions.next().unwrap().is_err());
+        assert!(extensions.next().is_none());
+    }
+
+    #[test]
+    fn rejects_wrong_extension_length() {
+        let data = extended_account(
+            ACCOUNT_TYPE_MINT,
+            Mint::LEN,
+            &[(ExtensionType::MintCloseAuthority as u16, &[1u8; 31])],
+        );
+
+        assert!(mint_extensions(&data)
+            .unwrap()
+            .find_extension::<MintCloseAuthority>()
+            .is_err());
+    }
+
+    #[test]
+    fn sizes_mints() {
+        assert_eq!(get_mint_len(&[]), Ok(Mint::LEN));
+        assert_eq!(
+            get_mint_len(&[ExtensionType::MintCloseAuthority]),
+            Ok(TLV_START + TLV_HEADER_LEN + 32)
+        );
+        assert_eq!(
+            get_mint_len(&[ExtensionType::TransferHook, ExtensionType::TransferHook]),
+            Ok(TLV_START + TLV_HEADER_LEN + 64)
+        );
+        assert_eq!(
+            get_mint_len(&[
+                ExtensionType::TransferFeeConfig,
+                ExtensionType::NonTransferable
+            ]),
+            Ok(TLV_START + TLV_HEADER_LEN + 108 + TLV_HEADER_LEN)
+        );
+        assert!(get_mint_len(&[ExtensionType::ImmutableOwner]).is_err());
+        assert!(get_mint_len(&[ExtensionType::TokenMetadata]).is_err());
+    }
+
+    fn transfer_fee(epoch: u64, maximum_fee: u64, basis_points: u16) -> TransferFee {
+        TransferFee {
+            epoch: epoch.to_le_bytes(),
+            maximum_fee: maximum_fee.to_le_bytes(),
+            transfer_fee_basis_points: basis_points.to_le_bytes(),
+        }
+    }
+
+    #[test]
+    fn calculates_transfer_fee() {
+        // 1%, rounded up, capped at 5_000
+        let fee = transfer_fee(0, 5_000, 100);
+        assert_eq!(fee.calculate_fee(0), Some(0));
+        assert_eq!(fee.calculate_fee(1), Some(1));
+        assert_eq!(fee.calculate_fee(10_000), Some(100));
+        assert_eq!(fee.calculate_fee(10_001), Some(101));
+        assert_eq!(fee.calculate_fee(u64::MAX), Some(5_000));
+
+        assert_eq!(transfer_fee(0, 5_000, 0).calculate_fee(10_000), Some(0));
+    }
+
+    #[test]
+    fn calculates_inverse_fee() {
+        let fee = transfer_fee(0, 5_000, 100);
+        for post_fee_amount in [0, 1, 99, 9_900, 123_456, 10_000_000] {
+            let pre_fee_amount = fee.calculate_pre_fee_amount(post_fee_amount).unwrap();
+            let inverse_fee = fee.calculate_inverse_fee(post_fee_amount).unwrap();
+            assert_eq!(fee.calculate_fee(pre_fee_amount), Some(inverse_fee));
+            assert_eq!(pre_fee_amount - inverse_fee, post_fee_amount);
+        }
+
+        // a 100% fee always takes the maximum fee
+        let fee = transfer_fee(0, 5_000, 10_000);
+        assert_eq!(fee.calculate_pre_fee_amount(1), Some(5_001));
+        assert_eq!(fee.calculate_inverse_fee(1), Some(5_000));
+    }
+
+    #[test]
+    fn picks_fee_by_epoch() {
+        let config = TransferFeeConfig {
+            transfer_fee_config_authority: [0; 32],
+            withdraw_withheld_authority: [0; 32],
+            withheld_amount: [0; 8],
+            older_transfer_fee: transfer_fee(0, u64::MAX, 100),
+            newer_transfer_fee: transfer_fee(10, u64::MAX, 200),
+        };
+        assert_eq!(config.epoch_fee(9).transfer_fee_basis_points(), 100);
+        assert_eq!(config.epoch_fee(10).transfer_fee_basis_points(), 200);
+    }
+}
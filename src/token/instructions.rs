@@ -0,0 +1,549 @@
+//! Token instruction builders missing from `pinocchio_token_2022`.
+//!
+//! `pinocchio_token_2022` builds the base instructions for whichever token program
+//! is passed in, but it can't sign with a multisig authority, append the accounts
+//! of a transfer hook, or read GetAccountDataSize's return data. These cover those
+//! cases and mirror its structs, with a `token_program` field used as the
+//! instruction's program id.
+
+use core::array;
+
+use pinocchio::{
+    account_info::AccountInfo,
+    cpi::{get_return_data, invoke_signed, invoke_signed_with_bounds},
+    instruction::{AccountMeta, Instruction, Signer},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+
+use crate::{
+    token::{
+        AuthorityType, TransferHookAccounts, MAX_MULTISIG_SIGNERS, MAX_TRANSFER_HOOK_ACCOUNTS,
+    },
+    MplUtilsError,
+};
+
+/// Most fixed accounts of an instruction passed to [`invoke_with_authority`]
+const MAX_FIXED_ACCOUNTS: usize = 4;
+
+/// Invokes a token instruction whose last account is the authority.
+///
+/// With `multisig_signers`, the authority is a token multisig account: it's passed
+/// as a plain readonly account and the member signers are appended after it.
+fn invoke_with_authority<const ACCOUNTS: usize>(
+    token_program: &Pubkey,
+    account_metas: [AccountMeta; ACCOUNTS],
+    account_infos: [&AccountInfo; ACCOUNTS],
+    instruction_data: &[u8],
+    multisig_signers: &[&AccountInfo],
+    signers: &[Signer],
+) -> ProgramResult {
+    if multisig_signers.is_empty() {
+        let instruction = Instruction {
+            program_id: token_program,
+            accounts: &account_metas,
+            data: instruction_data,
+        };
+
+        return invoke_signed(&instruction, &account_infos, signers);
+    }
+
+    if multisig_signers.len() > MAX_MULTISIG_SIGNERS {
+        return Err(MplUtilsError::TooManyMultisigSigners.into());
+    }
+
+    const MAX_ACCOUNTS: usize = MAX_FIXED_ACCOUNTS + MAX_MULTISIG_SIGNERS;
+
+    let authority = account_infos[ACCOUNTS - 1];
+    let mut metas: [AccountMeta; MAX_ACCOUNTS] =
+        array::from_fn(|_| AccountMeta::readonly(authority.key()));
+    let mut infos: [&AccountInfo; MAX_ACCOUNTS] = [authority; MAX_ACCOUNTS];
+
+    for (i, (meta, info)) in account_metas.into_iter().zip(account_infos).enumerate() {
+        metas[i] = meta;
+        infos[i] = info;
+    }
+
+    // the multisig account itself doesn't sign, its members do
+    metas[ACCOUNTS - 1] = AccountMeta::readonly(authority.key());
+
+    for (i, signer) in multisig_signers.iter().enumerate() {
+        metas[ACCOUNTS + i] = AccountMeta::readonly_signer(signer.key());
+        infos[ACCOUNTS + i] = signer;
+    }
+
+    let total_accounts = ACCOUNTS + multisig_signers.len();
+
+    let instruction = Instruction {
+        program_id: token_program,
+        accounts: &metas[..total_accounts],
+        data: instruction_data,
+    };
+
+    invoke_signed_with_bounds::<MAX_ACCOUNTS>(&instruction, &infos[..total_accounts], signers)
+}
+
+/// Transfer tokens from one token account to another, checking the mint decimals,
+/// with the accounts the mint's transfer hook requires appended.
+///
+/// ### Accounts:
+///   0. `[WRITE]` The source account.
+///   1. `[]` The token mint.
+///   2. `[WRITE]` The destination account.
+///   3. `[SIGNER]` The source account's owner/delegate.
+///   4. ..`4+M` `[]` The hook's extra accounts, the hook program and its
+///      validation account.
+pub(crate) struct TransferCheckedWithHook<'a> {
+    pub from: &'a AccountInfo,
+    pub mint: &'a AccountInfo,
+    pub to: &'a AccountInfo,
+    pub authority: &'a AccountInfo,
+    pub amount: u64,
+    pub decimals: u8,
+    pub transfer_hook: &'a TransferHookAccounts<'a>,
+    pub token_program: &'a Pubkey,
+}
+
+impl TransferCheckedWithHook<'_> {
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let account_metas: [AccountMeta; 4] = [
+            AccountMeta::writable(self.from.key()),
+            AccountMeta::readonly(self.mint.key()),
+            AccountMeta::writable(self.to.key()),
+            AccountMeta::readonly_signer(self.authority.key()),
+        ];
+
+        // -  [0]: instruction discriminator (1 byte, u8)
+        // -  [1..9]: amount (8 bytes, u64)
+        // -  [9]: decimals (1 byte, u8)
+        let mut instruction_data = [0u8; 10];
+        instruction_data[0] = 12;
+        instruction_data[1..9].copy_from_slice(&self.amount.to_le_bytes());
+        instruction_data[9] = self.decimals;
+
+        let account_infos = [self.from, self.mint, self.to, self.authority];
+
+        const MAX_ACCOUNTS: usize = 4 + MAX_TRANSFER_HOOK_ACCOUNTS;
+
+        let mut metas: [AccountMeta; MAX_ACCOUNTS] =
+            array::from_fn(|_| AccountMeta::readonly(self.mint.key()));
+        let mut infos: [&AccountInfo; MAX_ACCOUNTS] = [self.mint; MAX_ACCOUNTS];
+
+        let all_metas = account_metas
+            .into_iter()
+            .chain(self.transfer_hook.metas().iter().cloned());
+        let all_infos = account_infos
+            .into_iter()
+            .chain(self.transfer_hook.infos().iter().copied());
+        for (i, (meta, info)) in all_metas.zip(all_infos).enumerate() {
+            metas[i] = meta;
+            infos[i] = info;
+        }
+
+        let total_accounts = 4 + self.transfer_hook.metas().len();
+
+        let instruction = Instruction {
+            program_id: self.token_program,
+            accounts: &metas[..total_accounts],
+            data: &instruction_data,
+        };
+
+        invoke_signed_with_bounds::<MAX_ACCOUNTS>(&instruction, &infos[..total_accounts], signers)
+    }
+}
+
+/// Burn tokens by removing them from an account.
+///
+/// ### Accounts:
+///   0. `[WRITE]` The account to burn from.
+///   1. `[WRITE]` The token mint.
+///   2. `[SIGNER]` The account's owner/delegate, or a multisig followed by its signers.
+pub(crate) struct Burn<'a> {
+    pub account: &'a AccountInfo,
+    pub mint: &'a AccountInfo,
+    pub authority: &'a AccountInfo,
+    pub multisig_signers: &'a [&'a AccountInfo],
+    pub amount: u64,
+    pub token_program: &'a Pubkey,
+}
+
+impl Burn<'_> {
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let account_metas: [AccountMeta; 3] = [
+            AccountMeta::writable(self.account.key()),
+            AccountMeta::writable(self.mint.key()),
+            AccountMeta::readonly_signer(self.authority.key()),
+        ];
+
+        // -  [0]: instruction discriminator (1 byte, u8)
+        // -  [1..9]: amount (8 bytes, u64)
+        let mut instruction_data = [0u8; 9];
+        instruction_data[0] = 8;
+        instruction_data[1..9].copy_from_slice(&self.amount.to_le_bytes());
+
+        invoke_with_authority(
+            self.token_program,
+            account_metas,
+            [self.account, self.mint, self.authority],
+            &instruction_data,
+            self.multisig_signers,
+            signers,
+        )
+    }
+}
+
+/// Mint new tokens to an account.
+///
+/// ### Accounts:
+///   0. `[WRITE]` The mint.
+///   1. `[WRITE]` The account to mint tokens to.
+///   2. `[SIGNER]` The mint's minting authority, or a multisig followed by its signers.
+pub(crate) struct MintTo<'a> {
+    pub mint: &'a AccountInfo,
+    pub account: &'a AccountInfo,
+    pub mint_authority: &'a AccountInfo,
+    pub multisig_signers: &'a [&'a AccountInfo],
+    pub amount: u64,
+    pub token_program: &'a Pubkey,
+}
+
+impl MintTo<'_> {
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let account_metas: [AccountMeta; 3] = [
+            AccountMeta::writable(self.mint.key()),
+            AccountMeta::writable(self.account.key()),
+            AccountMeta::readonly_signer(self.mint_authority.key()),
+        ];
+
+        // -  [0]: instruction discriminator (1 byte, u8)
+        // -  [1..9]: amount (8 bytes, u64)
+        let mut instruction_data = [0u8; 9];
+        instruction_data[0] = 7;
+        instruction_data[1..9].copy_from_slice(&self.amount.to_le_bytes());
+
+        invoke_with_authority(
+            self.token_program,
+            account_metas,
+            [self.mint, self.account, self.mint_authority],
+            &instruction_data,
+            self.multisig_signers,
+            signers,
+        )
+    }
+}
+
+/// Close an account by transferring all its SOL to the destination account.
+///
+/// ### Accounts:
+///   0. `[WRITE]` The account to close.
+///   1. `[WRITE]` The destination account.
+///   2. `[SIGNER]` The account's owner, or a multisig followed by its signers.
+pub(crate) struct CloseAccount<'a> {
+    pub account: &'a AccountInfo,
+    pub destination: &'a AccountInfo,
+    pub authority: &'a AccountInfo,
+    pub multisig_signers: &'a [&'a AccountInfo],
+    pub token_program: &'a Pubkey,
+}
+
+impl CloseAccount<'_> {
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let account_metas: [AccountMeta; 3] = [
+            AccountMeta::writable(self.account.key()),
+            AccountMeta::writable(self.destination.key()),
+            AccountMeta::readonly_signer(self.authority.key()),
+        ];
+
+        // -  [0]: instruction discriminator (1 byte, u8)
+        let instruction_data = [9u8];
+
+        invoke_with_authority(
+            self.token_program,
+            account_metas,
+            [self.account, self.destination, self.authority],
+            &instruction_data,
+            self.multisig_signers,
+            signers,
+        )
+    }
+}
+
+/// Approve a delegate to transfer or burn up to `amount` tokens of an account.
+///
+/// ### Accounts:
+///   0. `[WRITE]` The source account.
+///   1. `[]` The delegate.
+///   2. `[SIGNER]` The source account's owner, or a multisig followed by its signers.
+pub(crate) struct Approve<'a> {
+    pub source: &'a AccountInfo,
+    pub delegate: &'a AccountInfo,
+    pub authority: &'a AccountInfo,
+    pub multisig_signers: &'a [&'a AccountInfo],
+    pub amount: u64,
+    pub token_program: &'a Pubkey,
+}
+
+impl Approve<'_> {
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let account_metas: [AccountMeta; 3] = [
+            AccountMeta::writable(self.source.key()),
+            AccountMeta::readonly(self.delegate.key()),
+            AccountMeta::readonly_signer(self.authority.key()),
+        ];
+
+        // -  [0]: instruction discriminator (1 byte, u8)
+        // -  [1..9]: amount (8 bytes, u64)
+        let mut instruction_data = [0u8; 9];
+        instruction_data[0] = 4;
+        instruction_data[1..9].copy_from_slice(&self.amount.to_le_bytes());
+
+        invoke_with_authority(
+            self.token_program,
+            account_metas,
+            [self.source, self.delegate, self.authority],
+            &instruction_data,
+            self.multisig_signers,
+            signers,
+        )
+    }
+}
+
+/// Approve a delegate to transfer or burn up to `amount` tokens of an account,
+/// checking the mint decimals.
+///
+/// ### Accounts:
+///   0. `[WRITE]` The source account.
+///   1. `[]` The token mint.
+///   2. `[]` The delegate.
+///   3. `[SIGNER]` The source account's owner, or a multisig followed by its signers.
+pub(crate) struct ApproveChecked<'a> {
+    pub source: &'a AccountInfo,
+    pub mint: &'a AccountInfo,
+    pub delegate: &'a AccountInfo,
+    pub authority: &'a AccountInfo,
+    pub multisig_signers: &'a [&'a AccountInfo],
+    pub amount: u64,
+    pub decimals: u8,
+    pub token_program: &'a Pubkey,
+}
+
+impl ApproveChecked<'_> {
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let account_metas: [AccountMeta; 4] = [
+            AccountMeta::writable(self.source.key()),
+            AccountMeta::readonly(self.mint.key()),
+            AccountMeta::readonly(self.delegate.key()),
+            AccountMeta::readonly_signer(self.authority.key()),
+        ];
+
+        // -  [0]: instruction discriminator (1 byte, u8)
+        // -  [1..9]: amount (8 bytes, u64)
+        // -  [9]: decimals (1 byte, u8)
+        let mut instruction_data = [0u8; 10];
+        instruction_data[0] = 13;
+        instruction_data[1..9].copy_from_slice(&self.amount.to_le_bytes());
+        instruction_data[9] = self.decimals;
+
+        invoke_with_authority(
+            self.token_program,
+            account_metas,
+            [self.source, self.mint, self.delegate, self.authority],
+            &instruction_data,
+            self.multisig_signers,
+            signers,
+        )
+    }
+}
+
+/// Revoke the delegate of an account.
+///
+/// ### Accounts:
+///   0. `[WRITE]` The source account.
+///   1. `[SIGNER]` The source account's owner, or a multisig followed by its signers.
+pub(crate) struct Revoke<'a> {
+    pub source: &'a AccountInfo,
+    pub authority: &'a AccountInfo,
+    pub multisig_signers: &'a [&'a AccountInfo],
+    pub token_program: &'a Pubkey,
+}
+
+impl Revoke<'_> {
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let account_metas: [AccountMeta; 2] = [
+            AccountMeta::writable(self.source.key()),
+            AccountMeta::readonly_signer(self.authority.key()),
+        ];
+
+        // -  [0]: instruction discriminator (1 byte, u8)
+        let instruction_data = [5u8];
+
+        invoke_with_authority(
+            self.token_program,
+            account_metas,
+            [self.source, self.authority],
+            &instruction_data,
+            self.multisig_signers,
+            signers,
+        )
+    }
+}
+
+/// Set a new authority of a mint or account, or remove it with `None`.
+///
+/// ### Accounts:
+///   0. `[WRITE]` The mint or account to change the authority of.
+///   1. `[SIGNER]` The current authority, or a multisig followed by its signers.
+pub(crate) struct SetAuthority<'a> {
+    pub account: &'a AccountInfo,
+    pub authority: &'a AccountInfo,
+    pub multisig_signers: &'a [&'a AccountInfo],
+    pub authority_type: AuthorityType,
+    pub new_authority: Option<&'a Pubkey>,
+    pub token_program: &'a Pubkey,
+}
+
+impl SetAuthority<'_> {
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let account_metas: [AccountMeta; 2] = [
+            AccountMeta::writable(self.account.key()),
+            AccountMeta::readonly_signer(self.authority.key()),
+        ];
+
+        // -  [0]: instruction discriminator (1 byte, u8)
+        // -  [1]: authority_type (1 byte, u8)
+        // -  [2]: new_authority presence flag (1 byte, u8)
+        // -  [3..35]: new_authority (optional, 32 bytes, Pubkey)
+        let mut instruction_data = [0u8; 35];
+        instruction_data[0] = 6;
+        instruction_data[1] = self.authority_type as u8;
+        let len = match self.new_authority {
+            Some(new_authority) => {
+                instruction_data[2] = 1;
+                instruction_data[3..35].copy_from_slice(new_authority);
+                35
+            }
+            None => 3,
+        };
+
+        invoke_with_authority(
+            self.token_program,
+            account_metas,
+            [self.account, self.authority],
+            &instruction_data[..len],
+            self.multisig_signers,
+            signers,
+        )
+    }
+}
+
+/// Freeze an initialized account using the mint's freeze authority.
+///
+/// ### Accounts:
+///   0. `[WRITE]` The account to freeze.
+///   1. `[]` The token mint.
+///   2. `[SIGNER]` The mint's freeze authority, or a multisig followed by its signers.
+pub(crate) struct FreezeAccount<'a> {
+    pub account: &'a AccountInfo,
+    pub mint: &'a AccountInfo,
+    pub freeze_authority: &'a AccountInfo,
+    pub multisig_signers: &'a [&'a AccountInfo],
+    pub token_program: &'a Pubkey,
+}
+
+impl FreezeAccount<'_> {
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let account_metas: [AccountMeta; 3] = [
+            AccountMeta::writable(self.account.key()),
+            AccountMeta::readonly(self.mint.key()),
+            AccountMeta::readonly_signer(self.freeze_authority.key()),
+        ];
+
+        // -  [0]: instruction discriminator (1 byte, u8)
+        let instruction_data = [10u8];
+
+        invoke_with_authority(
+            self.token_program,
+            account_metas,
+            [self.account, self.mint, self.freeze_authority],
+            &instruction_data,
+            self.multisig_signers,
+            signers,
+        )
+    }
+}
+
+/// Thaw a frozen account using the mint's freeze authority.
+///
+/// ### Accounts:
+///   0. `[WRITE]` The account to thaw.
+///   1. `[]` The token mint.
+///   2. `[SIGNER]` The mint's freeze authority, or a multisig followed by its signers.
+pub(crate) struct ThawAccount<'a> {
+    pub account: &'a AccountInfo,
+    pub mint: &'a AccountInfo,
+    pub freeze_authority: &'a AccountInfo,
+    pub multisig_signers: &'a [&'a AccountInfo],
+    pub token_program: &'a Pubkey,
+}
+
+impl ThawAccount<'_> {
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let account_metas: [AccountMeta; 3] = [
+            AccountMeta::writable(self.account.key()),
+            AccountMeta::readonly(self.mint.key()),
+            AccountMeta::readonly_signer(self.freeze_authority.key()),
+        ];
+
+        // -  [0]: instruction discriminator (1 byte, u8)
+        let instruction_data = [11u8];
+
+        invoke_with_authority(
+            self.token_program,
+            account_metas,
+            [self.account, self.mint, self.freeze_authority],
+            &instruction_data,
+            self.multisig_signers,
+            signers,
+        )
+    }
+}
+
+/// Get the size a token account for `mint` needs, including the extensions the
+/// mint requires on its token accounts. The size is returned as return data.
+///
+/// ### Accounts:
+///   0. `[]` The mint to calculate for.
+pub(crate) struct GetAccountDataSize<'a> {
+    pub mint: &'a AccountInfo,
+    pub token_program: &'a Pubkey,
+}
+
+impl GetAccountDataSize<'_> {
+    pub fn invoke(&self) -> Result<usize, ProgramError> {
+        let account_metas: [AccountMeta; 1] = [AccountMeta::readonly(self.mint.key())];
+
+        // -  [0]: instruction discriminator (1 byte, u8)
+        let instruction_data = [21u8];
+
+        let instruction = Instruction {
+            program_id: self.token_program,
+            accounts: &account_metas,
+            data: &instruction_data,
+        };
+
+        invoke_signed(&instruction, &[self.mint], &[])?;
+
+        let return_data = get_return_data().ok_or(ProgramError::InvalidAccountData)?;
+        if return_data.program_id() != self.token_program {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let size: [u8; 8] = return_data
+            .as_slice()
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+
+        Ok(u64::from_le_bytes(size) as usize)
+    }
+}
@@ -1,9 +1,16 @@
 pub use assertions::*;
 pub use cpi::*;
-use cpi_params::*;
+pub use cpi_params::*;
+pub use extensions::*;
+pub use state::*;
+pub use transfer_hook::*;
 pub use utils::*;
 
 mod assertions;
 mod cpi;
 mod cpi_params;
+mod extensions;
+mod instructions;
+mod state;
+mod transfer_hook;
 mod utils;
@@ -0,0 +1,62 @@
+use pinocchio::pubkey::Pubkey;
+
+use crate::assertions::{IsInitialized, Pack};
+
+/// Maximum number of signers of a token multisig account
+pub const MAX_MULTISIG_SIGNERS: usize = 11;
+
+/// Token multisig account, the layout is the same for Tokenkeg and Token-2022
+#[repr(C)]
+pub struct Multisig {
+    /// Number of signers required
+    m: u8,
+    /// Number of valid signers
+    n: u8,
+    /// Is `true` if this structure has been initialized
+    is_initialized: u8,
+    /// Signer public keys
+    signers: [Pubkey; MAX_MULTISIG_SIGNERS],
+}
+
+impl Multisig {
+    pub const LEN: usize = core::mem::size_of::<Multisig>();
+
+    /// # Safety
+    ///
+    /// `bytes` must be at least `Multisig::LEN` long.
+    #[inline(always)]
+    pub unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &Self {
+        &*(bytes.as_ptr() as *const Multisig)
+    }
+
+    pub fn m(&self) -> u8 {
+        self.m
+    }
+
+    pub fn n(&self) -> u8 {
+        self.n
+    }
+
+    /// The valid signers, the first `n` of the signers array
+    pub fn signers(&self) -> &[Pubkey] {
+        &self.signers[..(self.n as usize).min(MAX_MULTISIG_SIGNERS)]
+    }
+
+    pub fn is_initialized(&self) -> bool {
+        self.is_initialized == 1
+    }
+}
+
+impl Pack for Multisig {
+    const LEN: usize = Self::LEN;
+
+    unsafe fn from_bytes_unchecked(bytes: &[u8]) -> &Self {
+        Self::from_bytes_unchecked(bytes)
+    }
+}
+
+impl IsInitialized for Multisig {
+    fn is_initialized(&self) -> bool {
+        Self::is_initialized(self)
+    }
+}
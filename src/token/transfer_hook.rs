@@ -0,0 +1,754 @@
+//! Resolution of the extra accounts a Token-2022 transfer hook requires.
+//!
+//! A mint's `TransferHook` extension names a program Token-2022 invokes on every
+//! transfer. The hook's validation account, the PDA of `["extra-account-metas", mint]`
+//! under the hook program, holds an `ExtraAccountMetaList` describing the extra
+//! accounts of the hook's `Execute` instruction. A transfer CPI has to pass those
+//! accounts, followed by the hook program and the validation account. A hook
+//! without a validation account takes no extra accounts.
+
+use core::{array, mem::size_of, slice};
+
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::AccountMeta,
+    program_error::ProgramError,
+    pubkey::{try_find_program_address, Pubkey, MAX_SEEDS, MAX_SEED_LEN},
+};
+
+use crate::{token::get_extra_account_metas_address, MplUtilsError};
+
+/// Seed of the validation account, followed by the mint
+pub const EXTRA_ACCOUNT_METAS_SEED: &[u8] = b"extra-account-metas";
+
+/// Discriminator of the transfer hook `Execute` instruction, also the TLV type of
+/// its entry in the validation account
+pub const EXECUTE_DISCRIMINATOR: [u8; 8] = [105, 37, 101, 197, 75, 251, 102, 26];
+
+/// Most extra accounts a transfer hook can require
+pub const MAX_EXTRA_ACCOUNT_METAS: usize = 16;
+
+/// Extra accounts plus the hook program and the validation account
+pub const MAX_TRANSFER_HOOK_ACCOUNTS: usize = MAX_EXTRA_ACCOUNT_METAS + 2;
+
+/// Accounts of the `Execute` instruction before the extra accounts: source, mint,
+/// destination, authority and the validation account
+const EXECUTE_FIXED_ACCOUNTS: usize = 5;
+
+/// Size of the TLV type and length, and of the meta count in front of the metas
+const LIST_HEADER_LEN: usize = 8 + 4 + 4;
+
+/// One entry of an `ExtraAccountMetaList`
+#[repr(C)]
+pub struct ExtraAccountMeta {
+    discriminator: u8,
+    address_config: [u8; 32],
+    is_signer: u8,
+    is_writable: u8,
+}
+
+impl ExtraAccountMeta {
+    pub const LEN: usize = size_of::<Self>();
+
+    pub fn is_signer(&self) -> bool {
+        self.is_signer != 0
+    }
+
+    pub fn is_writable(&self) -> bool {
+        self.is_writable != 0
+    }
+
+    /// Resolves the address of this account. `accounts` are the `Execute`
+    /// accounts resolved so far, which seeds and pubkey data refer to by index.
+    fn resolve(
+        &self,
+        instruction_data: &[u8],
+        accounts: &[&AccountInfo],
+        hook_program_id: &Pubkey,
+    ) -> Result<Pubkey, ProgramError> {
+        match self.discriminator {
+            // fixed address
+            0 => Ok(self.address_config),
+            // address stored in instruction or account data
+            2 => pubkey_data(&self.address_config, instruction_data, accounts),
+            _ => {
+                let program_id = self.pda_program_id(accounts, hook_program_id)?;
+                let seeds = address_seeds(&self.address_config, instruction_data, accounts)?;
+
+                try_find_program_address(seeds.slices().as_slice(), program_id)
+                    .map(|(address, _bump)| address)
+                    .ok_or(ProgramError::InvalidSeeds)
+            }
+        }
+    }
+
+    /// Program this account is a PDA of, for the PDA discriminators
+    fn pda_program_id<'b>(
+        &self,
+        accounts: &[&'b AccountInfo],
+        hook_program_id: &'b Pubkey,
+    ) -> Result<&'b Pubkey, ProgramError> {
+        match self.discriminator {
+            // PDA of the hook program
+            1 => Ok(hook_program_id),
+            // PDA of the program at account index `discriminator - 128`
+            index @ 128.. => Ok(execute_account(accounts, index - 128)?.key()),
+            _ => Err(ProgramError::InvalidAccountData),
+        }
+    }
+}
+
+/// Reads the extra account metas of the `Execute` instruction from the data of a
+/// transfer hook validation account.
+pub fn extra_account_metas(data: &[u8]) -> Result<&[ExtraAccountMeta], ProgramError> {
+    // -  [0..8]: TLV type, the `Execute` discriminator
+    // -  [8..12]: TLV length (u32)
+    // -  [12..16]: number of metas (u32)
+    // -  [16..]: metas
+    if data.len() < LIST_HEADER_LEN || data[..8] != EXECUTE_DISCRIMINATOR {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let length = u32::from_le_bytes(data[8..12].try_into().unwrap()) as usize;
+    let count = u32::from_le_bytes(data[12..16].try_into().unwrap()) as usize;
+
+    let metas_len = count
+        .checked_mul(ExtraAccountMeta::LEN)
+        .ok_or(ProgramError::InvalidAccountData)?;
+    if metas_len + 4 > length || LIST_HEADER_LEN + metas_len > data.len() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // SAFETY: the length was checked above and `ExtraAccountMeta` only holds
+    // bytes, so it has an alignment of 1.
+    Ok(unsafe {
+        slice::from_raw_parts(
+            data[LIST_HEADER_LEN..].as_ptr() as *const ExtraAccountMeta,
+            count,
+        )
+    })
+}
+
+/// The extra accounts of a transfer hook, resolved from the accounts the program
+/// received, followed by the hook program and the validation account, if there's
+/// one. These are appended to a TransferChecked CPI.
+pub struct TransferHookAccounts<'a> {
+    metas: [AccountMeta<'a>; MAX_TRANSFER_HOOK_ACCOUNTS],
+    infos: [&'a AccountInfo; MAX_TRANSFER_HOOK_ACCOUNTS],
+    len: usize,
+}
+
+impl<'a> TransferHookAccounts<'a> {
+    /// Resolves the accounts `hook_program_id` requires to transfer `amount` of
+    /// `mint` from `source` to `destination`, looking them up in `accounts`.
+    ///
+    /// The validation account is optional: without it the hook program is the only
+    /// account added. Returns `NotEnoughAccountKeys` if another one wasn't passed in.
+    pub fn resolve(
+        hook_program_id: &Pubkey,
+        source: &'a AccountInfo,
+        mint: &'a AccountInfo,
+        destination: &'a AccountInfo,
+        authority: &'a AccountInfo,
+        amount: u64,
+        accounts: &'a [AccountInfo],
+    ) -> Result<Self, ProgramError> {
+        let find_account = |key: &Pubkey| {
+            accounts
+                .iter()
+                .find(|account| account.key() == key)
+                .ok_or(ProgramError::NotEnoughAccountKeys)
+        };
+
+        let hook_program = find_account(hook_program_id)?;
+        let validation_address = get_extra_account_metas_address(mint.key(), hook_program_id);
+        let validation_account = accounts
+            .iter()
+            .find(|account| account.key() == &validation_address);
+
+        Self::resolve_with_validation_account(
+            hook_program,
+            validation_account,
+            source,
+            mint,
+            destination,
+            authority,
+            amount,
+            accounts,
+        )
+    }
+
+    /// Same as [`TransferHookAccounts::resolve`] once the hook program and its
+    /// validation account were looked up.
+    #[allow(clippy::too_many_arguments)]
+    fn resolve_with_validation_account(
+        hook_program: &'a AccountInfo,
+        validation_account: Option<&'a AccountInfo>,
+        source: &'a AccountInfo,
+        mint: &'a AccountInfo,
+        destination: &'a AccountInfo,
+        authority: &'a AccountInfo,
+        amount: u64,
+        accounts: &'a [AccountInfo],
+    ) -> Result<Self, ProgramError> {
+        let find_account = |key: &Pubkey| {
+            accounts
+                .iter()
+                .find(|account| account.key() == key)
+                .ok_or(ProgramError::NotEnoughAccountKeys)
+        };
+
+        let mut hook_accounts = Self {
+            metas: array::from_fn(|_| AccountMeta::readonly(mint.key())),
+            infos: [mint; MAX_TRANSFER_HOOK_ACCOUNTS],
+            len: 0,
+        };
+
+        let Some(validation_account) = validation_account else {
+            hook_accounts.push(AccountMeta::readonly(hook_program.key()), hook_program);
+            return Ok(hook_accounts);
+        };
+
+        // -  [0..8]: instruction discriminator
+        // -  [8..16]: amount (8 bytes, u64)
+        let mut execute_data = [0u8; 16];
+        execute_data[0..8].copy_from_slice(&EXECUTE_DISCRIMINATOR);
+        execute_data[8..16].copy_from_slice(&amount.to_le_bytes());
+
+        let mut execute_accounts = [mint; EXECUTE_FIXED_ACCOUNTS + MAX_EXTRA_ACCOUNT_METAS];
+        execute_accounts[..EXECUTE_FIXED_ACCOUNTS].copy_from_slice(&[
+            source,
+            mint,
+            destination,
+            authority,
+            validation_account,
+        ]);
+
+        let data = validation_account.try_borrow_data()?;
+        let extra_metas = extra_account_metas(&data)?;
+        if extra_metas.len() > MAX_EXTRA_ACCOUNT_METAS {
+            return Err(MplUtilsError::TooManyExtraAccountMetas.into());
+        }
+
+        for (i, extra_meta) in extra_metas.iter().enumerate() {
+            let resolved_accounts = &execute_accounts[..EXECUTE_FIXED_ACCOUNTS + i];
+            let key = extra_meta.resolve(&execute_data, resolved_accounts, hook_program.key())?;
+            let account = find_account(&key)?;
+
+            execute_accounts[EXECUTE_FIXED_ACCOUNTS + i] = account;
+            hook_accounts.push(
+                AccountMeta::new(
+                    account.key(),
+                    extra_meta.is_writable(),
+                    extra_meta.is_signer(),
+                ),
+                account,
+            );
+        }
+
+        hook_accounts.push(AccountMeta::readonly(hook_program.key()), hook_program);
+        hook_accounts.push(
+            AccountMeta::readonly(validation_account.key()),
+            validation_account,
+        );
+
+        Ok(hook_accounts)
+    }
+
+    pub fn metas(&self) -> &[AccountMeta<'a>] {
+        &self.metas[..self.len]
+    }
+
+    pub fn infos(&self) -> &[&'a AccountInfo] {
+        &self.infos[..self.len]
+    }
+
+    fn push(&mut self, meta: AccountMeta<'a>, info: &'a AccountInfo) {
+        self.metas[self.len] = meta;
+        self.infos[self.len] = info;
+        self.len += 1;
+    }
+}
+
+fn execute_account<'a>(
+    accounts: &[&'a AccountInfo],
+    index: u8,
+) -> Result<&'a AccountInfo, ProgramError> {
+    accounts
+        .get(index as usize)
+        .copied()
+        .ok_or(ProgramError::InvalidAccountData)
+}
+
+fn data_range(data: &[u8], start: usize, len: usize) -> Result<&[u8], ProgramError> {
+    data.get(start..start + len)
+        .ok_or(ProgramError::InvalidAccountData)
+}
+
+/// Seeds copied out of the address config, instruction data and account data
+struct SeedBuffer {
+    bytes: [u8; MAX_SEEDS * MAX_SEED_LEN],
+    ends: [usize; MAX_SEEDS],
+    len: usize,
+}
+
+impl SeedBuffer {
+    fn push(&mut self, seed: &[u8]) -> Result<(), ProgramError> {
+        if self.len == MAX_SEEDS || seed.len() > MAX_SEED_LEN {
+            return Err(ProgramError::InvalidSeeds);
+        }
+
+        let start = if self.len == 0 {
+            0
+        } else {
+            self.ends[self.len - 1]
+        };
+        let end = start + seed.len();
+
+        self.bytes[start..end].copy_from_slice(seed);
+        self.ends[self.len] = end;
+        self.len += 1;
+
+        Ok(())
+    }
+
+    fn slices(&self) -> SeedSlices<'_> {
+        let mut slices: [&[u8]; MAX_SEEDS] = [&[]; MAX_SEEDS];
+        let mut start = 0;
+        for (slot, end) in slices.iter_mut().zip(&self.ends[..self.len]) {
+            *slot = &self.bytes[start..*end];
+            start = *end;
+        }
+
+        SeedSlices {
+            slices,
+            len: self.len,
+        }
+    }
+}
+
+/// The seeds of a [`SeedBuffer`] as the slices a PDA derivation takes
+struct SeedSlices<'a> {
+    slices: [&'a [u8]; MAX_SEEDS],
+    len: usize,
+}
+
+impl SeedSlices<'_> {
+    fn as_slice(&self) -> &[&[u8]] {
+        &self.slices[..self.len]
+    }
+}
+
+/// Reads the PDA seeds packed in `address_config`:
+/// * `[1, length, bytes..]` a literal
+/// * `[2, index, length]` a slice of the instruction data
+/// * `[3, index]` the key of an account
+/// * `[4, account_index, data_index, length]` a slice of an account's data
+fn address_seeds(
+    address_config: &[u8; 32],
+    instruction_data: &[u8],
+    accounts: &[&AccountInfo],
+) -> Result<SeedBuffer, ProgramError> {
+    let config_byte = |offset: usize| {
+        address_config
+            .get(offset)
+            .copied()
+            .ok_or(ProgramError::InvalidAccountData)
+    };
+
+    let mut seeds = SeedBuffer {
+        bytes: [0u8; MAX_SEEDS * MAX_SEED_LEN],
+        ends: [0; MAX_SEEDS],
+        len: 0,
+    };
+
+    let mut offset = 0;
+    // the config is zero padded after the last seed
+    while offset < address_config.len() && address_config[offset] != 0 {
+        match address_config[offset] {
+            1 => {
+                let length = config_byte(offset + 1)? as usize;
+                seeds.push(data_range(address_config, offset + 2, length)?)?;
+                offset += 2 + length;
+            }
+            2 => {
+                let index = config_byte(offset + 1)? as usize;
+                let length = config_byte(offset + 2)? as usize;
+                seeds.push(data_range(instruction_data, index, length)?)?;
+                offset += 3;
+            }
+            3 => {
+                let account = execute_account(accounts, config_byte(offset + 1)?)?;
+                seeds.push(account.key())?;
+                offset += 2;
+            }
+            4 => {
+                let account = execute_account(accounts, config_byte(offset + 1)?)?;
+                let data_index = config_byte(offset + 2)? as usize;
+                let length = config_byte(offset + 3)? as usize;
+                seeds.push(data_range(&account.try_borrow_data()?, data_index, length)?)?;
+                offset += 4;
+            }
+            _ => return Err(ProgramError::InvalidAccountData),
+        }
+    }
+
+    Ok(seeds)
+}
+
+/// Reads an address stored in data, as packed in `address_config`:
+/// * `[1, index]` at `index` in the instruction data
+/// * `[2, account_index, data_index]` at `data_index` in an account's data
+fn pubkey_data(
+    address_config: &[u8; 32],
+    instruction_data: &[u8],
+    accounts: &[&AccountInfo],
+) -> Result<Pubkey, ProgramError> {
+    let to_pubkey = |bytes: &[u8]| -> Result<Pubkey, ProgramError> {
+        bytes
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)
+    };
+
+    match address_config[0] {
+        1 => to_pubkey(data_range(
+            instruction_data,
+            address_config[1] as usize,
+            32,
+        )?),
+        2 => {
+            let account = execute_account(accounts, address_config[1])?;
+            let data = account.try_borrow_data()?;
+            to_pubkey(data_range(&data, address_config[2] as usize, 32)?)
+        }
+        _ => Err(ProgramError::InvalidAccountData),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::test_utils::TestAccount;
+
+    fn extra_account_meta_list(metas: &[[u8; ExtraAccountMeta::LEN]]) -> Vec<u8> {
+        let mut data = EXECUTE_DISCRIMINATOR.to_vec();
+        data.extend_from_slice(&(4 + metas.len() as u32 * 35).to_le_bytes());
+        data.extend_from_slice(&(metas.len() as u32).to_le_bytes());
+        for meta in metas {
+            data.extend_from_slice(meta);
+        }
+        data
+    }
+
+    fn literal_meta(address: Pubkey, is_signer: bool, is_writable: bool) -> [u8; 35] {
+        let mut meta = [0u8; 35];
+        meta[1..33].copy_from_slice(&address);
+        meta[33] = is_signer as u8;
+        meta[34] = is_writable as u8;
+        meta
+    }
+
+    #[test]
+    fn reads_extra_account_metas() {
+        let data = extra_account_meta_list(&[
+            literal_meta([1; 32], false, true),
+            literal_meta([2; 32], true, false),
+        ]);
+
+        let metas = extra_account_metas(&data).unwrap();
+        assert_eq!(metas.len(), 2);
+        assert_eq!(metas[0].discriminator, 0);
+        assert_eq!(metas[0].address_config, [1; 32]);
+        assert!(metas[0].is_writable() && !metas[0].is_signer());
+        assert!(metas[1].is_signer() && !metas[1].is_writable());
+    }
+
+    #[test]
+    fn reads_empty_extra_account_metas() {
+        let data = extra_account_meta_list(&[]);
+        assert!(extra_account_metas(&data).unwrap().is_empty());
+    }
+
+    #[test]
+    fn rejects_wrong_discriminator() {
+        let mut data = extra_account_meta_list(&[literal_meta([1; 32], false, false)]);
+        data[0] ^= 1;
+        assert!(extra_account_metas(&data).is_err());
+    }
+
+    #[test]
+    fn rejects_truncated_metas() {
+        let mut data = extra_account_meta_list(&[literal_meta([1; 32], false, false)]);
+        data.pop();
+        assert!(extra_account_metas(&data).is_err());
+    }
+
+    fn meta(discriminator: u8, config: &[u8], is_signer: bool, is_writable: bool) -> [u8; 35] {
+        let mut meta = [0u8; 35];
+        meta[0] = discriminator;
+        meta[1..1 + config.len()].copy_from_slice(config);
+        meta[33] = is_signer as u8;
+        meta[34] = is_writable as u8;
+        meta
+    }
+
+    fn address_config(config: &[u8]) -> [u8; 32] {
+        let mut address_config = [0u8; 32];
+        address_config[..config.len()].copy_from_slice(config);
+        address_config
+    }
+
+    fn execute_data(amount: u64) -> [u8; 16] {
+        let mut data = [0u8; 16];
+        data[0..8].copy_from_slice(&EXECUTE_DISCRIMINATOR);
+        data[8..16].copy_from_slice(&amount.to_le_bytes());
+        data
+    }
+
+    /// Source, mint, destination, authority and validation accounts of `Execute`,
+    /// with keys `[1; 32]` to `[5; 32]`. The source holds `[7; 32]` followed by
+    /// `[8; 32]` as data.
+    fn execute_accounts(validation_data: &[u8]) -> Vec<TestAccount> {
+        let mut source_data = [7u8; 64];
+        source_data[32..].fill(8);
+
+        vec![
+            TestAccount::new([1u8; 32], [0u8; 32], &source_data),
+            TestAccount::new([2u8; 32], [0u8; 32], &[]),
+            TestAccount::new([3u8; 32], [0u8; 32], &[]),
+            TestAccount::new([4u8; 32], [0u8; 32], &[]),
+            TestAccount::new([5u8; 32], [9u8; 32], validation_data),
+        ]
+    }
+
+    fn seeds(config: &[u8], amount: u64) -> Result<Vec<Vec<u8>>, ProgramError> {
+        let mut accounts = execute_accounts(&[]);
+        let infos: Vec<AccountInfo> = accounts.iter_mut().map(TestAccount::info).collect();
+        let infos: Vec<&AccountInfo> = infos.iter().collect();
+
+        let seeds = address_seeds(&address_config(config), &execute_data(amount), &infos)?;
+        let seeds = seeds.slices();
+        Ok(seeds.as_slice().iter().map(|seed| seed.to_vec()).collect())
+    }
+
+    #[test]
+    fn literal_seed() {
+        assert_eq!(seeds(&[1, 3, b'a', b'b', b'c'], 0).unwrap(), [b"abc"]);
+    }
+
+    #[test]
+    fn instruction_data_seed() {
+        // the amount
+        assert_eq!(
+            seeds(&[2, 8, 8], 42).unwrap(),
+            [42u64.to_le_bytes().to_vec()]
+        );
+        // past the end of the instruction data
+        assert_eq!(
+            seeds(&[2, 12, 8], 42),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+
+    #[test]
+    fn account_key_seed() {
+        // the mint
+        assert_eq!(seeds(&[3, 1], 0).unwrap(), [[2u8; 32]]);
+        // no account at index 5 yet
+        assert_eq!(seeds(&[3, 5], 0), Err(ProgramError::InvalidAccountData));
+    }
+
+    #[test]
+    fn account_data_seed() {
+        // 4 bytes across the source data
+        assert_eq!(seeds(&[4, 0, 30, 4], 0).unwrap(), [[7, 7, 8, 8]]);
+        // past the end of the source data
+        assert_eq!(
+            seeds(&[4, 0, 62, 4], 0),
+            Err(ProgramError::InvalidAccountData)
+        );
+        // no account at index 5 yet
+        assert_eq!(
+            seeds(&[4, 5, 0, 4], 0),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+
+    #[test]
+    fn combined_seeds() {
+        assert_eq!(
+            seeds(&[1, 1, b'x', 3, 2, 2, 8, 1, 4, 0, 32, 2], 5).unwrap(),
+            [vec![b'x'], vec![3u8; 32], vec![5], vec![8, 8]]
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_seed_type() {
+        assert_eq!(seeds(&[5, 1], 0), Err(ProgramError::InvalidAccountData));
+    }
+
+    #[test]
+    fn pubkey_data_address() {
+        let mut accounts = execute_accounts(&[]);
+        let infos: Vec<AccountInfo> = accounts.iter_mut().map(TestAccount::info).collect();
+        let infos: Vec<&AccountInfo> = infos.iter().collect();
+        let data = execute_data(0);
+
+        // the second key in the source data
+        assert_eq!(
+            pubkey_data(&address_config(&[2, 0, 32]), &data, &infos),
+            Ok([8u8; 32])
+        );
+        // past the end of the source data
+        assert_eq!(
+            pubkey_data(&address_config(&[2, 0, 33]), &data, &infos),
+            Err(ProgramError::InvalidAccountData)
+        );
+        // the instruction data only holds 16 bytes
+        assert_eq!(
+            pubkey_data(&address_config(&[1, 0]), &data, &infos),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+
+    #[test]
+    fn pda_program_ids() {
+        let mut accounts = execute_accounts(&[]);
+        let infos: Vec<AccountInfo> = accounts.iter_mut().map(TestAccount::info).collect();
+        let infos: Vec<&AccountInfo> = infos.iter().collect();
+        let hook_program_id = [9u8; 32];
+
+        let data = extra_account_meta_list(&[
+            meta(1, &[], false, false),
+            meta(128 + 2, &[], false, false),
+            meta(128 + 5, &[], false, false),
+            meta(0, &[], false, false),
+        ]);
+        let metas = extra_account_metas(&data).unwrap();
+
+        assert_eq!(
+            metas[0].pda_program_id(&infos, &hook_program_id),
+            Ok(&hook_program_id)
+        );
+        // the destination
+        assert_eq!(
+            metas[1].pda_program_id(&infos, &hook_program_id),
+            Ok(&[3u8; 32])
+        );
+        // no account at index 5 yet
+        assert_eq!(
+            metas[2].pda_program_id(&infos, &hook_program_id),
+            Err(ProgramError::InvalidAccountData)
+        );
+        assert_eq!(
+            metas[3].pda_program_id(&infos, &hook_program_id),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+
+    /// Resolves the hook accounts for a validation account holding `metas`,
+    /// looking them up in the `Execute` accounts and `extra_accounts`
+    fn resolve_keys(
+        metas: &[[u8; ExtraAccountMeta::LEN]],
+        extra_accounts: &[Pubkey],
+    ) -> Result<Vec<(Pubkey, bool, bool)>, ProgramError> {
+        let mut accounts = execute_accounts(&extra_account_meta_list(metas));
+        // the hook program
+        accounts.push(TestAccount::new([9u8; 32], [0u8; 32], &[]));
+        for key in extra_accounts {
+            accounts.push(TestAccount::new(*key, [0u8; 32], &[]));
+        }
+        let infos: Vec<AccountInfo> = accounts.iter_mut().map(TestAccount::info).collect();
+
+        let hook_accounts = TransferHookAccounts::resolve_with_validation_account(
+            &infos[5],
+            Some(&infos[4]),
+            &infos[0],
+            &infos[1],
+            &infos[2],
+            &infos[3],
+            0,
+            &infos,
+        )?;
+
+        Ok(hook_accounts
+            .metas()
+            .iter()
+            .map(|meta| (*meta.pubkey, meta.is_signer, meta.is_writable))
+            .collect())
+    }
+
+    #[test]
+    fn resolves_hook_accounts() {
+        let keys = resolve_keys(
+            &[
+                meta(0, &[6u8; 32], false, true),
+                // the second key in the source data
+                meta(2, &[2, 0, 32], true, false),
+            ],
+            &[[6u8; 32], [8u8; 32]],
+        )
+        .unwrap();
+
+        assert_eq!(
+            keys,
+            [
+                ([6u8; 32], false, true),
+                ([8u8; 32], true, false),
+                // the hook program and the validation account
+                ([9u8; 32], false, false),
+                ([5u8; 32], false, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn resolves_without_validation_account() {
+        let mut accounts = execute_accounts(&[]);
+        // the hook program
+        accounts.push(TestAccount::new([9u8; 32], [0u8; 32], &[]));
+        let infos: Vec<AccountInfo> = accounts.iter_mut().map(TestAccount::info).collect();
+
+        let hook_accounts = TransferHookAccounts::resolve_with_validation_account(
+            &infos[5], None, &infos[0], &infos[1], &infos[2], &infos[3], 0, &infos,
+        )
+        .unwrap();
+
+        assert_eq!(hook_accounts.metas().len(), 1);
+        assert_eq!(hook_accounts.metas()[0].pubkey, &[9u8; 32]);
+        assert_eq!(hook_accounts.infos()[0].key(), &[9u8; 32]);
+    }
+
+    #[test]
+    fn rejects_missing_extra_account() {
+        assert_eq!(
+            resolve_keys(&[meta(0, &[6u8; 32], false, true)], &[]),
+            Err(ProgramError::NotEnoughAccountKeys)
+        );
+    }
+
+    #[test]
+    fn rejects_too_many_extra_account_metas() {
+        let metas = [meta(0, &[6u8; 32], false, false); MAX_EXTRA_ACCOUNT_METAS + 1];
+
+        assert_eq!(
+            resolve_keys(&metas, &[[6u8; 32]]),
+            Err(MplUtilsError::TooManyExtraAccountMetas.into())
+        );
+    }
+
+    #[test]
+    fn resolve_requires_hook_program() {
+        let mut accounts = execute_accounts(&[]);
+        let infos: Vec<AccountInfo> = accounts.iter_mut().map(TestAccount::info).collect();
+
+        assert_eq!(
+            TransferHookAccounts::resolve(
+                &[9u8; 32], &infos[0], &infos[1], &infos[2], &infos[3], 0, &infos,
+            )
+            .err(),
+            Some(ProgramError::NotEnoughAccountKeys)
+        );
+    }
+}
@@ -0,0 +1,114 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey::{find_program_address, Pubkey},
+};
+use pinocchio_associated_token_account::ID as ASSOCIATED_TOKEN_PROGRAM_ID;
+use pinocchio_token::state::{Mint, TokenAccount};
+
+use crate::token::{
+    mint_extensions, Multisig, ToMint, TransferFee, TransferFeeConfig, TransferHook,
+    EXTRA_ACCOUNT_METAS_SEED,
+};
+
+/// Derives the associated token account address of `wallet` for `mint`.
+/// `token_program` is part of the seeds, so the same wallet and mint give a
+/// different address under Tokenkeg and Token-2022.
+pub fn get_associated_token_address(
+    wallet: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+) -> Pubkey {
+    find_program_address(
+        &[wallet.as_ref(), token_program.as_ref(), mint.as_ref()],
+        &ASSOCIATED_TOKEN_PROGRAM_ID,
+    )
+    .0
+}
+
+/// Derives the transfer hook validation account of `mint`, which lists the extra
+/// accounts `hook_program_id` requires on transfers.
+pub fn get_extra_account_metas_address(mint: &Pubkey, hook_program_id: &Pubkey) -> Pubkey {
+    find_program_address(&[EXTRA_ACCOUNT_METAS_SEED, mint.as_ref()], hook_program_id).0
+}
+
+/// Program the given mint's `TransferHook` extension invokes on transfers, if
+/// the mint has one and it's set.
+pub fn get_transfer_hook_program_id(
+    mint_info: &AccountInfo,
+) -> Result<Option<Pubkey>, ProgramError> {
+    let data = mint_info.try_borrow_data()?;
+    let transfer_hook = mint_extensions(&data)?.find_extension::<TransferHook>()?;
+
+    Ok(transfer_hook.and_then(TransferHook::program_id).copied())
+}
+
+/// Offset of the `AccountType` byte Token-2022 writes right after the base
+/// token account when an account carries extensions.
+pub(crate) const ACCOUNT_TYPE_OFFSET: usize = TokenAccount::LEN;
+
+/// `AccountType` of an extended Token-2022 mint.
+pub(crate) const ACCOUNT_TYPE_MINT: u8 = 1;
+
+/// `AccountType` of an extended Token-2022 token account.
+pub(crate) const ACCOUNT_TYPE_ACCOUNT: u8 = 2;
+
+/// Whether `data` is laid out as a mint: the base mint length, or an extended
+/// Token-2022 account tagged as a mint.
+pub(crate) fn is_mint_data(data: &[u8]) -> bool {
+    has_layout(data, Mint::LEN, ACCOUNT_TYPE_MINT)
+}
+
+/// Whether `data` is laid out as a token account: the base token account length,
+/// or an extended Token-2022 account tagged as a token account.
+pub(crate) fn is_token_account_data(data: &[u8]) -> bool {
+    has_layout(data, TokenAccount::LEN, ACCOUNT_TYPE_ACCOUNT)
+}
+
+/// Whether `data` is `base_len` long, or an extended Token-2022 account tagged
+/// with `account_type`.
+pub(crate) fn has_layout(data: &[u8], base_len: usize, account_type: u8) -> bool {
+    if data.len() == base_len {
+        return true;
+    }
+
+    // a multisig is the one account longer than a token account without an `AccountType`
+    data.len() > ACCOUNT_TYPE_OFFSET
+        && data.len() != Multisig::LEN
+        && data[ACCOUNT_TYPE_OFFSET] == account_type
+}
+
+/// Fee withheld when sending `amount` of the given mint during `epoch`, usually
+/// `Clock::get()?.epoch`. Mints without a `TransferFeeConfig` charge no fee, so
+/// the recipient gets `amount - fee`.
+pub fn calculate_transfer_fee(
+    mint_info: &AccountInfo,
+    amount: u64,
+    epoch: u64,
+) -> Result<u64, ProgramError> {
+    transfer_fee(mint_info, epoch, |fee| fee.calculate_fee(amount))
+}
+
+/// Fee withheld on a transfer of the given mint that delivers `amount` during
+/// `epoch`, so the sender has to send `amount + fee`.
+pub fn calculate_inverse_fee(
+    mint_info: &AccountInfo,
+    amount: u64,
+    epoch: u64,
+) -> Result<u64, ProgramError> {
+    transfer_fee(mint_info, epoch, |fee| fee.calculate_inverse_fee(amount))
+}
+
+fn transfer_fee(
+    mint_info: &AccountInfo,
+    epoch: u64,
+    calculate: impl FnOnce(&TransferFee) -> Option<u64>,
+) -> Result<u64, ProgramError> {
+    drop(mint_info.try_to_mint(ProgramError::UninitializedAccount)?);
+
+    let data = mint_info.try_borrow_data()?;
+    match mint_extensions(&data)?.find_extension::<TransferFeeConfig>()? {
+        Some(config) => calculate(config.epoch_fee(epoch)).ok_or(ProgramError::ArithmeticOverflow),
+        None => Ok(0),
+    }
+}